@@ -0,0 +1,39 @@
+//!
+//! A 1D cellular automaton written with the Store Comonad
+//!
+//! Run with `cargo run --example cellular_automaton [rule]`, the rule number is
+//! a Wolfram code and defaults to rule 90, the Sierpinski triangle.
+//!
+
+use funlib::Comonad;
+use funlib::store::Store;
+
+const WIDTH: i32 = 63;
+const GENERATIONS: usize = 32;
+
+/// The board is a ring, positions past either edge wrap around
+type Board = Store<i32, bool>;
+
+/// The next state of the focused cell, looked up in the rule by the cell and its two neighbours
+fn step(rule: u8) -> impl Fn(&Board) -> bool {
+  move |w| {
+    let left = w.peeks(|i| (i + WIDTH - 1) % WIDTH) as u8;
+    let right = w.peeks(|i| (i + 1) % WIDTH) as u8;
+    let pattern = (left << 2) | ((w.extract() as u8) << 1) | right;
+    rule >> pattern & 1 == 1
+  }
+}
+
+fn render(w: &Board) -> String {
+  (0..WIDTH).map(|i| if w.peek(&i) { '#' } else { ' ' }).collect()
+}
+
+fn main() {
+  let rule = std::env::args().nth(1).and_then(|r| r.parse().ok()).unwrap_or(90);
+  let mut board = Store::new(|i: &i32| *i == WIDTH / 2, 0);
+  for _ in 0..GENERATIONS {
+    println!("{}", render(&board));
+    // extend is lazy, storing each generation keeps a step from recomputing all earlier ones
+    board = board.extend(step(rule)).tabulate(0..WIDTH);
+  }
+}
@@ -3,16 +3,37 @@
 //!
 
 /// Derive HKT macro to create Higer Kinded Types
+///
+/// Single parameter types are written as `hkt!(Vec)`, types with a second
+/// parameter that stays fixed are written as `hkt!(Result<_, E>)`, where `_`
+/// marks the parameter that gets mapped over. The generated `Kind` impl also
+/// provides the `HKT` and `HKST` helpers. `hkt!(Result<_, _>)` maps over both
+/// parameters and creates a `Kind2` impl instead.
 #[macro_export]
 macro_rules! hkt {
+  ($t:ident<_, _>) => {
+    impl<X, Y> Kind2 for $t<X, Y> {
+      type A = X;
+      type B = Y;
+      type Target<C, D> = $t<C, D>;
+    }
+  };
   ($t:ident) => {
-    impl<B, C> HKT<C> for $t<B> {
+    impl<B> Kind for $t<B> {
       type A = B;
-      type M = $t<C>;
+      type Target<C> = $t<C>;
     }
-    impl<'a, B: 'a> HKST<'a, B> for $t<B> {
-      type A = &'a B;
-      type M = $t<&'a B>;
+  };
+  ($t:ident<_, $e:ident>) => {
+    impl<B, $e> Kind for $t<B, $e> {
+      type A = B;
+      type Target<C> = $t<C, $e>;
+    }
+  };
+  ($t:ident<$e:ident, _>) => {
+    impl<B, $e> Kind for $t<$e, B> {
+      type A = B;
+      type Target<C> = $t<$e, C>;
     }
   };
 }
@@ -43,3 +64,118 @@ pub fn compose_two<A, B, C, G, F>(f: F, g: G) -> impl Fn(A) -> C
 where F: Fn(A) -> B, G: Fn(B) -> C {
     move |x| g(f(x))
 }
+
+/// Do notation for Monads, desugars to `Monad::bind` and `Applicative::pure_`
+///
+/// Supported statements:
+///
+/// * `x <- expr;` binds the value inside the Monad `expr` to `x` (cloned), `_ <- expr;` ignores it
+/// * `(a, b) <- expr;` destructures a tuple value
+/// * `let pat = expr;` is a plain let binding
+/// * `guard cond;` stops with `Alternative::empty()` when `cond` is false
+/// * `expr;` runs a Monad and ignores its value
+///
+/// The last line is either `pure expr`, which lifts the value with `Applicative::pure_`,
+/// or an expression that already is the Monad.
+///
+/// # Examples
+///
+/// ```
+/// use funlib::mdo;
+/// let r = mdo! {
+///   x <- Some(1);
+///   let y = x + 1;
+///   guard y > 1;
+///   z <- Some(y * 2);
+///   pure x + z
+/// };
+/// assert_eq!(Some(5), r);
+/// ```
+#[macro_export]
+macro_rules! mdo {
+  (let $p:ident : $t:ty = $e:expr ; $($rest:tt)+) => {
+    { let $p: $t = $e; $crate::mdo!($($rest)+) }
+  };
+  (let $p:pat = $e:expr ; $($rest:tt)+) => {
+    { let $p = $e; $crate::mdo!($($rest)+) }
+  };
+  (guard $e:expr ; $($rest:tt)+) => {
+    if $e { $crate::mdo!($($rest)+) } else { funlib::Alternative::empty() }
+  };
+  (pure $e:expr) => {
+    funlib::Applicative::pure_($e)
+  };
+  (_ <- $e:expr ; $($rest:tt)+) => {
+    funlib::Monad::bind(&$e, |_| { $crate::mdo!($($rest)+) })
+  };
+  ($p:ident <- $e:expr ; $($rest:tt)+) => {
+    funlib::Monad::bind(&$e, |__mdo_value| {
+      let $p = ::std::clone::Clone::clone(__mdo_value);
+      $crate::mdo!($($rest)+)
+    })
+  };
+  (($($p:ident),+) <- $e:expr ; $($rest:tt)+) => {
+    funlib::Monad::bind(&$e, |__mdo_value| {
+      let ($($p),+) = ::std::clone::Clone::clone(__mdo_value);
+      $crate::mdo!($($rest)+)
+    })
+  };
+  ($e:expr ; $($rest:tt)+) => {
+    funlib::Monad::bind(&$e, |_| { $crate::mdo!($($rest)+) })
+  };
+  ($e:expr) => {
+    $e
+  };
+}
+
+/// Combines up to 12 Applicatives with a function, desugars to `Functor::fmap` for a single value
+/// and to `Applicative::map2` .. `Applicative::map12` otherwise
+///
+/// # Examples
+///
+/// ```
+/// use funlib::map_n;
+/// let r = map_n!(Some(1), Some(2), Some(3) => |a, b, c| a + b + c);
+/// assert_eq!(Some(6), r);
+/// let r: Result<i32, &str> = map_n!(Ok(1), Ok(2), Err("c"), Err("d") => |a, b, c, d| a + b + c + d);
+/// assert_eq!(Err("c"), r);
+/// ```
+#[macro_export]
+macro_rules! map_n {
+  ($fa:expr => $f:expr) => {
+    funlib::Functor::fmap(&$fa, $f)
+  };
+  ($fa:expr, $fb:expr => $f:expr) => {
+    funlib::Applicative::map2(&$fa, &$fb, $f)
+  };
+  ($fa:expr, $fb:expr, $fc:expr => $f:expr) => {
+    funlib::Applicative::map3(&$fa, &$fb, &$fc, $f)
+  };
+  ($fa:expr, $fb:expr, $fc:expr, $fd:expr => $f:expr) => {
+    funlib::Applicative::map4(&$fa, &$fb, &$fc, &$fd, $f)
+  };
+  ($fa:expr, $fb:expr, $fc:expr, $fd:expr, $fe:expr => $f:expr) => {
+    funlib::Applicative::map5(&$fa, &$fb, &$fc, &$fd, &$fe, $f)
+  };
+  ($fa:expr, $fb:expr, $fc:expr, $fd:expr, $fe:expr, $ff:expr => $f:expr) => {
+    funlib::Applicative::map6(&$fa, &$fb, &$fc, &$fd, &$fe, &$ff, $f)
+  };
+  ($fa:expr, $fb:expr, $fc:expr, $fd:expr, $fe:expr, $ff:expr, $fg:expr => $f:expr) => {
+    funlib::Applicative::map7(&$fa, &$fb, &$fc, &$fd, &$fe, &$ff, &$fg, $f)
+  };
+  ($fa:expr, $fb:expr, $fc:expr, $fd:expr, $fe:expr, $ff:expr, $fg:expr, $fh:expr => $f:expr) => {
+    funlib::Applicative::map8(&$fa, &$fb, &$fc, &$fd, &$fe, &$ff, &$fg, &$fh, $f)
+  };
+  ($fa:expr, $fb:expr, $fc:expr, $fd:expr, $fe:expr, $ff:expr, $fg:expr, $fh:expr, $fi:expr => $f:expr) => {
+    funlib::Applicative::map9(&$fa, &$fb, &$fc, &$fd, &$fe, &$ff, &$fg, &$fh, &$fi, $f)
+  };
+  ($fa:expr, $fb:expr, $fc:expr, $fd:expr, $fe:expr, $ff:expr, $fg:expr, $fh:expr, $fi:expr, $fj:expr => $f:expr) => {
+    funlib::Applicative::map10(&$fa, &$fb, &$fc, &$fd, &$fe, &$ff, &$fg, &$fh, &$fi, &$fj, $f)
+  };
+  ($fa:expr, $fb:expr, $fc:expr, $fd:expr, $fe:expr, $ff:expr, $fg:expr, $fh:expr, $fi:expr, $fj:expr, $fk:expr => $f:expr) => {
+    funlib::Applicative::map11(&$fa, &$fb, &$fc, &$fd, &$fe, &$ff, &$fg, &$fh, &$fi, &$fj, &$fk, $f)
+  };
+  ($fa:expr, $fb:expr, $fc:expr, $fd:expr, $fe:expr, $ff:expr, $fg:expr, $fh:expr, $fi:expr, $fj:expr, $fk:expr, $fl:expr => $f:expr) => {
+    funlib::Applicative::map12(&$fa, &$fb, &$fc, &$fd, &$fe, &$ff, &$fg, &$fh, &$fi, &$fj, &$fk, &$fl, $f)
+  };
+}
@@ -0,0 +1,200 @@
+//!
+//! SemigroupK, MonoidK and Alternative implementations and tests
+//!
+
+use crate::{Alternative, MonoidK, Monoid, Semigroup, SemigroupK};
+
+impl<A: Clone> SemigroupK for Option<A> {
+  fn combine_k(&self, other: &Self) -> Self {
+    match self {
+      Some(_) => self.clone(),
+      None => other.clone(),
+    }
+  }
+}
+
+impl<A: Clone> MonoidK for Option<A> {
+  fn empty_k() -> Self {
+    None
+  }
+}
+
+impl<A: Clone> Alternative for Option<A> {
+  fn some<F>(mut f: F) -> Option<Vec<A>> where F: FnMut() -> Self {
+    let mut r = vec![f()?];
+    r.extend(Self::many(f)?);
+    Some(r)
+  }
+
+  fn many<F>(mut f: F) -> Option<Vec<A>> where F: FnMut() -> Self {
+    let mut r = vec![];
+    while let Some(a) = f() {
+      r.push(a);
+    }
+    Some(r)
+  }
+}
+
+impl<A: Clone> SemigroupK for Vec<A> {
+  fn combine_k(&self, other: &Self) -> Self {
+    let mut r = self.clone();
+    r.extend_from_slice(other);
+    r
+  }
+}
+
+impl<A: Clone> MonoidK for Vec<A> {
+  fn empty_k() -> Self {
+    vec![]
+  }
+}
+
+/// Every sequence that picks one value from each of the leading levels, followed by the empty sequence
+fn sequences<A: Clone>(levels: &[Vec<A>]) -> Vec<Vec<A>> {
+  match levels.split_first() {
+    None => vec![vec![]],
+    Some((first, rest)) => {
+      let tails = sequences(rest);
+      let mut r: Vec<Vec<A>> = first.iter().flat_map(|a| tails.iter().map(move |t| {
+        let mut v = vec![a.clone()];
+        v.extend_from_slice(t);
+        v
+      })).collect();
+      r.push(vec![]);
+      r
+    }
+  }
+}
+
+/// Runs `f` until it returns an empty Vec
+fn levels<A, F>(mut f: F) -> Vec<Vec<A>> where F: FnMut() -> Vec<A> {
+  let mut r = vec![];
+  loop {
+    let l = f();
+    if l.is_empty() {
+      return r;
+    }
+    r.push(l);
+  }
+}
+
+impl<A: Clone> Alternative for Vec<A> {
+  fn some<F>(f: F) -> Vec<Vec<A>> where F: FnMut() -> Self {
+    let mut r = sequences(&levels(f));
+    r.pop();
+    r
+  }
+
+  fn many<F>(f: F) -> Vec<Vec<A>> where F: FnMut() -> Self {
+    sequences(&levels(f))
+  }
+}
+
+/// Keeps the first `Ok`, the errors are combined when both sides failed
+impl<A: Clone, E: Semigroup> SemigroupK for Result<A, E> {
+  fn combine_k(&self, other: &Self) -> Self {
+    match (self, other) {
+      (Ok(_), _) => self.clone(),
+      (Err(a), Err(b)) => Err(a.mappend(b)),
+      (Err(_), Ok(_)) => other.clone(),
+    }
+  }
+}
+
+impl<A: Clone, E: Monoid> MonoidK for Result<A, E> {
+  fn empty_k() -> Self {
+    Err(E::mempty())
+  }
+}
+
+impl<A: Clone, E: Monoid> Alternative for Result<A, E> {
+  fn some<F>(mut f: F) -> Result<Vec<A>, E> where F: FnMut() -> Self {
+    let mut r = vec![f()?];
+    r.extend(Self::many(f)?);
+    Ok(r)
+  }
+
+  fn many<F>(mut f: F) -> Result<Vec<A>, E> where F: FnMut() -> Self {
+    let mut r = vec![];
+    while let Ok(a) = f() {
+      r.push(a);
+    }
+    Ok(r)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::Alternative;
+
+  #[cfg(test)]
+  mod laws {
+    use crate::{Alternative, Applicative, MonoidK, SemigroupK};
+
+    macro_rules! laws {
+      ($n: ident, $t: ty, $v: expr, $v2: expr, $v3: expr, $f: expr) => {
+        #[allow(non_snake_case)]
+        #[test]
+        fn $n() {
+          let (v, v2, v3): ($t, $t, $t) = ($v, $v2, $v3);
+          assert_eq!(v, <$t>::empty_k().combine_k(&v));
+          assert_eq!(v, v.combine_k(&<$t>::empty_k()));
+          assert_eq!(v.combine_k(&v2.combine_k(&v3)), (v.combine_k(&v2)).combine_k(&v3));
+          assert_eq!(<$t>::empty(), <$t>::empty().ap($f));
+          assert_eq!(v.alt(&v2), v.combine_k(&v2));
+        }
+      };
+    }
+    laws!(Option, Option<i32>, Some(5), Some(6), Some(10), Some(|x: &i32| x + 1));
+    laws!(OptionNone, Option<i32>, None, Some(6), None, Some(|x: &i32| x + 1));
+    laws!(Vec, Vec<i32>, vec![5], vec![6, 7], vec![10], vec![|x: &i32| x + 1]);
+    laws!(Result, Result<i32, u8>, Err(5), Ok(6), Err(10), Ok(|x: &i32| x + 1));
+    laws!(ResultErr, Result<i32, u8>, Err(5), Err(6), Err(10), Ok(|x: &i32| x + 1));
+  }
+
+  #[test]
+  fn option() {
+    assert_eq!(Some(1), Some(1).alt(&Some(2)));
+    assert_eq!(Some(2), None.alt(&Some(2)));
+    assert_eq!(Some(()), Option::guard(true));
+    assert_eq!(None, Option::guard(false));
+    assert_eq!(Some(None), None::<i32>.optional());
+  }
+
+  #[test]
+  fn option_some_many() {
+    let mut it = vec![1, 2].into_iter();
+    assert_eq!(Some(vec![1, 2]), Option::some(|| it.next()));
+    assert_eq!(None, Option::some(|| it.next()));
+    assert_eq!(Some(vec![]), Option::many(|| it.next()));
+  }
+
+  #[test]
+  fn vec() {
+    assert_eq!(vec![1, 2, 3], vec![1, 2].alt(&vec![3]));
+    assert_eq!(vec![()], Vec::guard(true));
+    assert_eq!(Vec::<()>::new(), Vec::guard(false));
+    assert_eq!(vec![Some(1), None], vec![1].optional());
+  }
+
+  #[test]
+  fn vec_some_many() {
+    let mut runs = vec![vec![], vec![3], vec![1, 2]];
+    assert_eq!(vec![vec![1, 3], vec![1], vec![2, 3], vec![2]], Vec::some(|| runs.pop().unwrap()));
+    let mut runs = vec![vec![], vec![1]];
+    assert_eq!(vec![vec![1], vec![]], Vec::many(|| runs.pop().unwrap()));
+    assert_eq!(Vec::<Vec<i32>>::new(), Vec::<i32>::some(Vec::new));
+    assert_eq!(vec![Vec::<i32>::new()], Vec::<i32>::many(Vec::new));
+  }
+
+  #[test]
+  fn result() {
+    let e: Result<i32, u32> = Err(1);
+    assert_eq!(Ok(1), e.alt(&Ok(1)));
+    assert_eq!(Err(2), e.alt(&e));
+    assert_eq!(Err::<(), u32>(0), Result::guard(false));
+    let mut it = vec![Ok(1), Ok(2), Err(3)].into_iter();
+    assert_eq!(Ok(vec![1, 2]), Result::many(|| it.next().unwrap()));
+    assert_eq!(Err::<Vec<i32>, u32>(4), Result::some(|| Err(4)));
+  }
+}
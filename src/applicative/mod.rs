@@ -2,53 +2,63 @@
 //! Applicative implementations and tests
 //! 
 use crate::Applicative;
-use crate::HKT;
 use std::rc::Rc;
 
-impl<A, B> Applicative<B> for Option<A> {
-  fn pure_(b: B) -> <Self as HKT<B>>::M {
-    Some(b)
+impl<A> Applicative for Option<A> {
+  fn pure_(a: A) -> Self {
+    Some(a)
   }
 
-  fn ap<F>(&self, of: <Self as HKT<F>>::M) -> Option<B> where  F: Fn(&A) -> B {
+  fn ap<B, F>(&self, of: Option<F>) -> Option<B> where F: Fn(&A) -> B {
     match (self, of) {
-      (&Some(ref a), Some(ref f)) => Some(f(a)),
+      (Some(a), Some(f)) => Some(f(a)),
       (_, _) => None
     }
   }
 }
 
-impl<A, B> Applicative<B> for Box<A> {
-  fn pure_(b: B) -> <Self as HKT<B>>::M {
-    Box::new(b)
+impl<A> Applicative for Box<A> {
+  fn pure_(a: A) -> Self {
+    Box::new(a)
   }
 
-  fn ap<F>(&self, of: <Self as HKT<F>>::M) -> Box<B> where F: Fn(&A) -> B {
+  fn ap<B, F>(&self, of: Box<F>) -> Box<B> where F: Fn(&A) -> B {
     Box::new(of(self))
   }
 }
 
-impl<A, B> Applicative<B> for Rc<A> {
-  fn pure_(b: B) -> <Self as HKT<B>>::M {
-    Rc::new(b)
+impl<A> Applicative for Rc<A> {
+  fn pure_(a: A) -> Self {
+    Rc::new(a)
   }
 
-  fn ap<F>(&self, of: <Self as HKT<F>>::M) -> Rc<B> where F: Fn(&A) -> B {
+  fn ap<B, F>(&self, of: Rc<F>) -> Rc<B> where F: Fn(&A) -> B {
     Rc::new(of(self))
   }
 }
 
-impl<A, B> Applicative<B> for Vec<A> {
-  fn pure_(b: B) -> <Self as HKT<B>>::M {
-    vec![b]
+/// Applies every function to every value, like `bind`, see `ZipList` for the zipping behavior
+impl<A> Applicative for Vec<A> {
+  fn pure_(a: A) -> Self {
+    vec![a]
   }
 
-  fn ap<F>(&self, of: <Self as HKT<F>>::M) -> Vec<B> where F: Fn(&A) -> B {
-    let mut result = vec![];
-    for (i,f) in of.into_iter().enumerate() {
-      result.push(f(&self[i]))
+  fn ap<B, F>(&self, of: Vec<F>) -> Vec<B> where F: Fn(&A) -> B {
+    self.iter().flat_map(|a| of.iter().map(move |f| f(a))).collect()
+  }
+}
+
+impl<A, E: Clone> Applicative for Result<A, E> {
+  fn pure_(a: A) -> Self {
+    Ok(a)
+  }
+
+  fn ap<B, F>(&self, of: Result<F, E>) -> Result<B, E> where F: Fn(&A) -> B {
+    match (self, of) {
+      (Ok(a), Ok(f)) => Ok(f(a)),
+      (Err(e), _) => Err(e.clone()),
+      (_, Err(e)) => Err(e),
     }
-    result
   }
 }
 
@@ -57,6 +67,38 @@ mod test {
   use crate::Applicative;
   use std::rc::Rc;
 
+  #[cfg(test)]
+  mod laws {
+    use crate::{Applicative, Functor, Monad};
+    use crate::nonempty::NonEmpty;
+    use std::rc::Rc;
+
+    macro_rules! laws {
+      ($n: ident, $t: ty, $ft: ty, $v: expr, $fs: expr) => {
+        #[allow(non_snake_case)]
+        #[test]
+        fn $n() {
+          let (v, fs): ($t, $ft) = ($v, $fs);
+          assert_eq!(v, v.ap(<$ft>::pure_(|x| *x)));
+          assert_eq!(<$t>::pure_(3), <$t>::pure_(2).ap(<$ft>::pure_(|x| x + 1)));
+          // ap agrees with bind, the values of self are the outer loop
+          assert_eq!(v.ap(fs.clone()), v.bind(|a| fs.fmap(|f| f(a))));
+        }
+      };
+    }
+    laws!(Option, Option<i32>, Option<fn(&i32) -> i32>, Some(5), Some(|x| x + 1));
+    laws!(OptionNone, Option<i32>, Option<fn(&i32) -> i32>, None, Some(|x| x + 1));
+    laws!(Box, Box<i32>, Box<fn(&i32) -> i32>, Box::new(5), Box::new(|x| x + 1));
+    laws!(Rc, Rc<i32>, Rc<fn(&i32) -> i32>, Rc::new(5), Rc::new(|x| x + 1));
+    laws!(Result, Result<i32, String>, Result<fn(&i32) -> i32, String>, Ok(5), Ok(|x| x + 1));
+    laws!(ResultErr, Result<i32, String>, Result<fn(&i32) -> i32, String>, Err("e".to_string()), Ok(|x| x + 1));
+    laws!(Vec, Vec<i32>, Vec<fn(&i32) -> i32>, vec![1, 2, 3], vec![|x| x + 1, |x| x * 10]);
+    laws!(VecEmpty, Vec<i32>, Vec<fn(&i32) -> i32>, vec![], vec![|x| x + 1]);
+    laws!(VecNoFunctions, Vec<i32>, Vec<fn(&i32) -> i32>, vec![1, 2], vec![]);
+    laws!(NonEmpty, NonEmpty<i32>, NonEmpty<fn(&i32) -> i32>, NonEmpty { head: 1, tail: vec![2, 3] },
+      NonEmpty { head: |x| x + 1, tail: vec![|x| x * 10] });
+  }
+
   fn double(i: &i32) -> i32 { i * 2 }
 
   #[test]
@@ -85,6 +127,68 @@ mod test {
     let f1: &dyn Fn(&i32) -> i32 = &|x| x + 6;
     let f2: &dyn Fn(&i32) -> i32 = &|x| x * x;
     let f3: &dyn Fn(&i32) -> i32 = &|x| x * x * x;
-    assert_eq!(vec![7,4,27], vec![1,2,3].ap(vec![f1,f2,f3]))
+    assert_eq!(vec![7,1,1,8,4,8,9,9,27], vec![1,2,3].ap(vec![f1,f2,f3]));
+    assert_eq!(Vec::<i32>::new(), vec![1,2,3].ap(Vec::<&dyn Fn(&i32) -> i32>::new()));
+    assert_eq!(vec![7], vec![1].ap(vec![f1]));
+  }
+
+  #[test]
+  fn vec_map_n() {
+    assert_eq!(vec![(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')], vec![1, 2].zip(&vec!['a', 'b']));
+    assert_eq!(vec![11, 21, 12, 22], vec![1, 2].map2(&vec![10, 20], |a, b| a + b));
+    assert_eq!(vec![1, 1, 2, 2], vec![1, 2].ap_first(&vec!['a', 'b']));
+    let digits = vec![1, 2].map4(&vec![0], &vec![3, 4], &vec![5], |a, b, c, d| a * 1000 + b * 100 + c * 10 + d);
+    assert_eq!(vec![1035, 1045, 2035, 2045], digits);
+  }
+
+  #[test]
+  fn result() {
+    let f: &dyn Fn(&i32) -> i32 = &|x| x * 2;
+    assert_eq!(Ok::<i32, &str>(4), Ok(2).ap(Ok(f)));
+    assert_eq!(Ok::<i32, &str>(4), Ok(2).ap(Ok(&double)));
+    assert_eq!(Err::<i32, &str>("no value"), Err("no value").ap(Ok(f)));
+    assert_eq!(Err::<i32, &str>("no function"), Ok(2).ap(Err::<&dyn Fn(&i32) -> i32, _>("no function")));
+    assert_eq!(Result::<i32, &str>::pure_(3), Ok(3));
+  }
+
+  #[test]
+  fn map_n() {
+    assert_eq!(Some(3), Some(1).map2(&Some(2), |a, b| a + b));
+    assert_eq!(None, Some(1).map3(&None::<i32>, &Some(3), |a, b, c| a + b + c));
+    assert_eq!(Box::new("1-2".to_string()), Box::new(1).map2(&Box::new(2), |a, b| format!("{}-{}", a, b)));
+    assert_eq!(Rc::new(6), Rc::new(1).map3(&Rc::new(2), &Rc::new(3), |a, b, c| a * b * c));
+    assert_eq!(Err::<i32, &str>("b"), Ok(1).map3(&Err("b"), &Err("c"), |a, b: &i32, c: &i32| a + b + c));
+    let r = Some(1).map12(&Some(2), &Some(3), &Some(4), &Some(5), &Some(6), &Some(7), &Some(8), &Some(9), &Some(10), &Some(11), &Some(12),
+      |a, b, c, d, e, f, g, h, i, j, k, l| a + b + c + d + e + f + g + h + i + j + k + l);
+    assert_eq!(Some(78), r);
+  }
+
+  #[test]
+  fn map_n_accumulates_in_order() {
+    use crate::validation::Validation::{self, *};
+    let a: Validation<Vec<&str>, i32> = Failure(vec!["a"]);
+    let b: Validation<Vec<&str>, i32> = Success(2);
+    let c: Validation<Vec<&str>, i32> = Failure(vec!["c"]);
+    assert_eq!(Failure(vec!["a", "c"]), a.map3(&b, &c, |a, b, c| a + b + c));
+    assert_eq!(Success(4), b.map2(&b, |a, b| a + b));
+    let d: Validation<Vec<&str>, i32> = Failure(vec!["d"]);
+    assert_eq!(Failure(vec!["a", "c", "d"]), a.map5(&b, &c, &b, &d, |a, b, c, d, e| a + b + c + d + e));
+    assert_eq!(Success(8), b.map4(&b, &b, &b, |a, b, c, d| a + b + c + d));
   }
-}
\ No newline at end of file
+
+  #[test]
+  fn zip_and_ap_first_second() {
+    assert_eq!(Some((1, "a")), Applicative::zip(&Some(1), &Some("a")));
+    assert_eq!(Ok::<_, &str>(1), Ok(1).ap_first(&Ok::<_, &str>("a")));
+    assert_eq!(Ok::<_, &str>("a"), Ok(1).ap_second(&Ok::<_, &str>("a")));
+    assert_eq!(Err::<i32, &str>("e"), Ok(1).ap_first(&Err::<(), &str>("e")));
+    assert_eq!(None, None::<i32>.ap_second(&Some("a")));
+  }
+
+  #[test]
+  fn map_n_macro() {
+    assert_eq!(Some(2), map_n!(Some(1) => |a| a + 1));
+    assert_eq!(Some(6), map_n!(Some(1), Some(2), Some(3) => |a, b, c| a + b + c));
+    assert_eq!(Err::<i32, &str>("x"), map_n!(Ok(1), Err("x") => |a: &i32, b: &i32| a + b));
+  }
+}
@@ -0,0 +1,79 @@
+//!
+//! Bifunctor implementations and tests
+//!
+
+use crate::Bifunctor;
+
+impl<A, E> Bifunctor for Result<A, E> {
+  fn bimap<C, D, F, G>(&self, f: F, g: G) -> Result<C, D> where F: Fn(&A) -> C, G: Fn(&E) -> D {
+    match self {
+      Ok(a) => Ok(f(a)),
+      Err(e) => Err(g(e)),
+    }
+  }
+}
+
+impl<A, B> Bifunctor for (A, B) {
+  fn bimap<C, D, F, G>(&self, f: F, g: G) -> (C, D) where F: Fn(&A) -> C, G: Fn(&B) -> D {
+    (f(&self.0), g(&self.1))
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::{Bifunctor, Kind2};
+
+  #[cfg(test)]
+  mod laws {
+    use crate::Bifunctor;
+    use crate::validation::Validation;
+
+    macro_rules! laws {
+      ($n: ident, $v: expr) => {
+        #[test]
+        fn $n() {
+          let v = $v;
+          let (f1, f2) = (|a: &i32| a + 2, |a: &i32| a * 5);
+          let (g1, g2) = (|b: &u8| u16::from(*b) + 1, |b: &u16| b * 3);
+          assert_eq!(v, v.bimap(|a| *a, |b| *b));
+          assert_eq!(v, v.first(|a| *a).second(|b| *b));
+          assert_eq!(v.bimap(|a| f2(&f1(a)), |b| g2(&g1(b))), v.bimap(f1, g1).bimap(f2, g2));
+          assert_eq!(v.bimap(f1, g1), v.first(f1).second(g1));
+          assert_eq!(v.bimap(f1, g1), v.second(g1).first(f1));
+        }
+      };
+    }
+    laws!(result_ok, Ok::<i32, u8>(5));
+    laws!(result_err, Err::<i32, u8>(5));
+    laws!(pair, (5i32, 7u8));
+    laws!(validation_success, Validation::<i32, u8>::Success(5));
+    laws!(validation_failure, Validation::<i32, u8>::Failure(5));
+  }
+
+  #[test]
+  fn result() {
+    let ok: Result<i32, &str> = Ok(1);
+    let err: Result<i32, &str> = Err("nope");
+    assert_eq!(Ok(2), ok.bimap(|a| a + 1, |e| e.len()));
+    assert_eq!(Err(4), err.bimap(|a| a + 1, |e| e.len()));
+    assert_eq!(Ok::<&str, &str>("1"), ok.first(|_| "1"));
+    assert_eq!(Err::<i32, String>("NOPE".to_string()), err.second(|e| e.to_uppercase()));
+  }
+
+  #[test]
+  fn pair() {
+    assert_eq!((2, "a!".to_string()), (1, "a").bimap(|a| a * 2, |b| format!("{}!", b)));
+    assert_eq!(("a", 1), ("a", "b").second(|b| b.len()));
+  }
+
+  #[test]
+  fn generic() {
+    fn describe<T: Bifunctor<A = i32, B = &'static str>>(t: &T) -> T::Target<String, usize> {
+      t.bimap(|a| a.to_string(), |b| b.len())
+    }
+    assert_eq!(("3".to_string(), 3), describe(&(3, "abc")));
+    assert_eq!(Err::<String, usize>(2), describe(&Err("ab")));
+    fn swap_types<T: Kind2<A = i32, B = u8>>() -> Option<T::Target<u8, i32>> { None }
+    assert_eq!(None::<(u8, i32)>, swap_types::<(i32, u8)>());
+  }
+}
@@ -0,0 +1,256 @@
+//!
+//! Contravariant implementations and tests
+//!
+
+use crate::{Contravariant, Kind};
+use funlib_macros::compose_two;
+use std::cmp::Ordering;
+use std::rc::Rc;
+
+hkt!(Predicate);
+hkt!(Comparator);
+hkt!(Op<R, _>);
+
+/// A test on values of type A
+///
+/// # Examples
+///
+/// ```
+/// use funlib::contravariant::Predicate;
+/// let small = Predicate::new(|i: &i32| *i < 10);
+/// let positive = Predicate::new(|i: &i32| *i > 0);
+/// let digit = small.and(&positive);
+/// assert!(digit.test(&5));
+/// assert!(!digit.test(&-5));
+/// ```
+pub struct Predicate<A>(Rc<dyn Fn(&A) -> bool>);
+
+impl<A> Predicate<A> {
+  /// Create a Predicate from a function
+  pub fn new<F>(f: F) -> Self where F: Fn(&A) -> bool + 'static {
+    Predicate(Rc::new(f))
+  }
+
+  /// Run the test on a value
+  pub fn test(&self, a: &A) -> bool {
+    (self.0)(a)
+  }
+
+  /// Passes when both Predicates pass
+  pub fn and(&self, other: &Self) -> Self where A: 'static {
+    let (p, q) = (self.clone(), other.clone());
+    Predicate::new(move |a| p.test(a) && q.test(a))
+  }
+
+  /// Passes when either Predicate passes
+  pub fn or(&self, other: &Self) -> Self where A: 'static {
+    let (p, q) = (self.clone(), other.clone());
+    Predicate::new(move |a| p.test(a) || q.test(a))
+  }
+
+  /// Passes when this Predicate fails
+  pub fn negate(&self) -> Self where A: 'static {
+    let p = self.clone();
+    Predicate::new(move |a| !p.test(a))
+  }
+}
+
+impl<A> Clone for Predicate<A> {
+  fn clone(&self) -> Self {
+    Predicate(self.0.clone())
+  }
+}
+
+impl<A: 'static> Contravariant for Predicate<A> {
+  fn contramap<B: 'static, F>(self, f: F) -> Predicate<B> where F: Fn(&B) -> A + 'static {
+    Predicate::new(move |b| self.test(&f(b)))
+  }
+}
+
+/// An ordering of values of type A
+///
+/// # Examples
+///
+/// ```
+/// use funlib::Contravariant;
+/// use funlib::contravariant::Comparator;
+/// let by_length = Comparator::<usize>::natural().contramap(|s: &&str| s.len());
+/// let mut words = vec!["ccc", "a", "bb"];
+/// by_length.sort(&mut words);
+/// assert_eq!(vec!["a", "bb", "ccc"], words);
+/// ```
+pub struct Comparator<A>(Rc<CompareFn<A>>);
+
+type CompareFn<A> = dyn Fn(&A, &A) -> Ordering;
+
+impl<A> Comparator<A> {
+  /// Create a Comparator from a function
+  pub fn new<F>(f: F) -> Self where F: Fn(&A, &A) -> Ordering + 'static {
+    Comparator(Rc::new(f))
+  }
+
+  /// The natural ordering of A
+  pub fn natural() -> Self where A: Ord + 'static {
+    Comparator::new(A::cmp)
+  }
+
+  /// Compare two values
+  pub fn compare(&self, a: &A, b: &A) -> Ordering {
+    (self.0)(a, b)
+  }
+
+  /// The opposite ordering
+  pub fn reverse(&self) -> Self where A: 'static {
+    let c = self.clone();
+    Comparator::new(move |a, b| c.compare(b, a))
+  }
+
+  /// Break ties of this Comparator with the next one
+  pub fn then(&self, next: &Self) -> Self where A: 'static {
+    let (c, d) = (self.clone(), next.clone());
+    Comparator::new(move |a, b| c.compare(a, b).then_with(|| d.compare(a, b)))
+  }
+
+  /// Sort a slice with this Comparator, the sort is stable
+  pub fn sort(&self, values: &mut [A]) {
+    values.sort_by(|a, b| self.compare(a, b))
+  }
+}
+
+impl<A> Clone for Comparator<A> {
+  fn clone(&self) -> Self {
+    Comparator(self.0.clone())
+  }
+}
+
+impl<A: 'static> Contravariant for Comparator<A> {
+  fn contramap<B: 'static, F>(self, f: F) -> Comparator<B> where F: Fn(&B) -> A + 'static {
+    Comparator::new(move |a, b| self.compare(&f(a), &f(b)))
+  }
+}
+
+/// A boxed function from A to R that is mapped over its input
+///
+/// `Box<dyn Fn(A) -> R>` can't be Contravariant itself, its `Kind` is the one of
+/// `Box` and maps the boxed value. Wrap it in an Op to `contramap` it, or use
+/// `Profunctor::lmap` on the box.
+///
+/// # Examples
+///
+/// ```
+/// use funlib::Contravariant;
+/// use funlib::contravariant::Op;
+/// let len = Op::new(|s: String| s.len());
+/// let digits = len.contramap(|i: &i32| i.to_string());
+/// assert_eq!(3, digits.run(100));
+/// ```
+pub struct Op<R, A>(Box<dyn Fn(A) -> R>);
+
+impl<R, A> Op<R, A> {
+  /// Create an Op from a function
+  pub fn new<F>(f: F) -> Self where F: Fn(A) -> R + 'static {
+    Op(Box::new(f))
+  }
+
+  /// Run the function on a value
+  pub fn run(&self, a: A) -> R {
+    (self.0)(a)
+  }
+}
+
+impl<R, A> From<Box<dyn Fn(A) -> R>> for Op<R, A> {
+  fn from(f: Box<dyn Fn(A) -> R>) -> Self {
+    Op(f)
+  }
+}
+
+impl<R: 'static, A: 'static> Contravariant for Op<R, A> {
+  fn contramap<B: 'static, F>(self, f: F) -> Op<R, B> where F: Fn(&B) -> A + 'static {
+    Op(Box::new(compose_two(move |b: B| f(&b), self.0)))
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::Contravariant;
+  use super::{Comparator, Op, Predicate};
+  use std::cmp::Ordering;
+
+  #[cfg(test)]
+  mod laws {
+    use crate::Contravariant;
+    use super::{Comparator, Op, Predicate};
+
+    #[test]
+    fn predicate() {
+      let p = Predicate::new(|i: &i32| i % 3 == 0);
+      let (f, g) = (|s: &String| s.len() as i32, |i: &u8| "x".repeat(*i as usize));
+      let id = p.clone().contramap(|i: &i32| *i);
+      let composed = p.clone().contramap(move |i: &u8| f(&g(i)));
+      let chained = p.contramap(f).contramap(g);
+      for i in 0..10 {
+        assert_eq!(i % 3 == 0, id.test(&i));
+        assert_eq!(composed.test(&(i as u8)), chained.test(&(i as u8)));
+      }
+    }
+
+    #[test]
+    fn comparator() {
+      let c = Comparator::<i32>::natural();
+      let (f, g) = (|s: &String| s.len() as i32, |i: &u8| "x".repeat(*i as usize));
+      let id = c.clone().contramap(|i: &i32| *i);
+      let composed = c.clone().contramap(move |i: &u8| f(&g(i)));
+      let chained = c.contramap(f).contramap(g);
+      for (a, b) in [(1, 2), (2, 2), (3, 1)] {
+        assert_eq!(a.cmp(&b), id.compare(&a, &b));
+        assert_eq!(composed.compare(&(a as u8), &(b as u8)), chained.compare(&(a as u8), &(b as u8)));
+      }
+    }
+
+    #[test]
+    fn op() {
+      let len = || Op::new(|s: String| s.len());
+      let (f, g) = (|i: &i32| "x".repeat(*i as usize), |b: &u8| *b as i32);
+      let id = len().contramap(|s: &String| s.clone());
+      let composed = len().contramap(move |b: &u8| f(&g(b)));
+      let chained = len().contramap(f).contramap(g);
+      for i in 0..5u8 {
+        assert_eq!(i as usize, id.run("x".repeat(i as usize)));
+        assert_eq!(composed.run(i), chained.run(i));
+      }
+    }
+  }
+
+  #[test]
+  fn predicate() {
+    let small = Predicate::new(|i: &i32| *i < 10);
+    let even = Predicate::new(|i: &i32| i % 2 == 0);
+    assert!(small.and(&even).test(&4));
+    assert!(!small.and(&even).test(&5));
+    assert!(small.or(&even).test(&12));
+    assert!(small.negate().test(&11));
+    let short = small.contramap(|s: &&str| s.len() as i32);
+    assert!(short.test(&"hello"));
+    assert!(!short.test(&"hello world"));
+  }
+
+  #[test]
+  fn comparator() {
+    let by_len = Comparator::<usize>::natural().contramap(|s: &&str| s.len());
+    let by_len_then_text = by_len.then(&Comparator::natural());
+    assert_eq!(Ordering::Less, by_len.compare(&"b", &"aa"));
+    assert_eq!(Ordering::Equal, by_len.compare(&"b", &"a"));
+    assert_eq!(Ordering::Greater, by_len_then_text.compare(&"b", &"a"));
+    let mut words = vec!["bb", "c", "aa", "a"];
+    by_len_then_text.reverse().sort(&mut words);
+    assert_eq!(vec!["bb", "aa", "c", "a"], words);
+  }
+
+  #[test]
+  fn op() {
+    let boxed: Box<dyn Fn(i32) -> i32> = Box::new(|i| i * 2);
+    let twice_len = Op::from(boxed).contramap(|s: &&str| s.len() as i32);
+    assert_eq!(6, twice_len.run("abc"));
+    assert_eq!(0, twice_len.run(""));
+  }
+}
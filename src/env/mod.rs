@@ -0,0 +1,105 @@
+//!
+//! Env implementations and tests
+//!
+
+use crate::{Comonad, Functor, Kind};
+
+hkt!(Env<E, _>);
+
+/// A value paired with a read only environment, the Comonad dual of a reader
+///
+/// # Examples
+///
+/// ```
+/// use funlib::Comonad;
+/// use funlib::env::Env;
+/// let price = Env::new(15, 200);
+/// let with_tax = price.extend(|w| w.value + w.value * w.env / 100);
+/// assert_eq!(230, with_tax.extract());
+/// assert_eq!(&15, with_tax.ask());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Env<E, A> {
+  /// The environment
+  pub env: E,
+  /// The value at the focus
+  pub value: A,
+}
+
+impl<E, A> Env<E, A> {
+  /// Create an Env from an environment and a value
+  pub fn new(env: E, value: A) -> Self {
+    Env { env, value }
+  }
+
+  /// The environment
+  pub fn ask(&self) -> &E {
+    &self.env
+  }
+
+  /// A value computed from the environment
+  pub fn asks<B, F>(&self, f: F) -> B where F: FnOnce(&E) -> B {
+    f(&self.env)
+  }
+
+  /// Change the environment, the value is kept
+  pub fn local<F>(self, f: F) -> Self where F: FnOnce(E) -> E {
+    Env { env: f(self.env), value: self.value }
+  }
+}
+
+impl<E: Clone, A> Functor for Env<E, A> {
+  fn fmap<B, F>(&self, f: F) -> Env<E, B> where F: Fn(&A) -> B {
+    Env { env: self.env.clone(), value: f(&self.value) }
+  }
+}
+
+impl<E: Clone, A: Clone> Comonad for Env<E, A> {
+  fn extract(&self) -> A {
+    self.value.clone()
+  }
+
+  fn extend<B: 'static, F>(&self, f: F) -> Env<E, B> where F: Fn(&Self) -> B + 'static {
+    Env { env: self.env.clone(), value: f(self) }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::{Comonad, Functor};
+  use super::Env;
+
+  #[cfg(test)]
+  mod laws {
+    use crate::{Comonad, Functor};
+    use super::Env;
+
+    type Scaled = Env<i32, i32>;
+
+    #[test]
+    fn functor() {
+      let w = Env::new(2, 5);
+      assert_eq!(w, w.fmap(|x| *x));
+      assert_eq!(w.fmap(|x| compose!(|x| x + 2, |x| x * 5)(x)), w.fmap(|x| x + 2).fmap(|x| x * 5));
+    }
+
+    #[test]
+    fn comonad() {
+      let w = Env::new(2, 5);
+      let (f, g) = (|w: &Scaled| w.value * w.env, |w: &Scaled| w.value + w.env);
+      assert_eq!(w, w.extend(|w| w.extract()));
+      assert_eq!(f(&w), w.extend(f).extract());
+      assert_eq!(w.extend(g).extend(f), w.extend(move |w| f(&w.extend(g))));
+    }
+  }
+
+  #[test]
+  fn env() {
+    let w = Env::new("config", 3);
+    assert_eq!(&"config", w.ask());
+    assert_eq!(6, w.asks(|e| e.len()));
+    assert_eq!(Env::new("other", 3), w.local(|_| "other"));
+    assert_eq!(Env::new("config", "3".to_string()), w.fmap(|a| a.to_string()));
+    assert_eq!(Env::new("config", w), w.duplicate());
+  }
+}
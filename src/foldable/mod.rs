@@ -3,64 +3,156 @@
 //!
 //!
 use crate::Foldable::*;
+use crate::{Monoid, Semigroup};
+use std::collections::{btree_set, hash_set, linked_list, vec_deque, binary_heap};
+use std::collections::{BTreeSet, BinaryHeap, HashSet, LinkedList, VecDeque};
+use std::hash::Hash;
+use std::rc::Rc;
 
-impl <A, B> FoldableB<B> for Vec<A> {
-  fn fold_right<F>(&self, z: B, f: F) -> B where F: Fn(&Self::A, B) -> B {
-    let mut r: B = z;
-    for x in self.iter().rev() {
-      r = f(x, r);
+macro_rules! foldable {
+  ($t:ident, $it:ty) => {
+    impl<A> Foldable for $t<A> {
+      type Iter<'a> = $it where A: 'a;
+      fn iter(&self) -> Self::Iter<'_> {
+        self.into_iter()
+      }
     }
-    r
-  }
-  fn fold_left<F>(&self, z: B, f: F) -> B where F: Fn(B, &Self::A) -> B {
-    self.iter().fold(z, f)
-  }
+  };
+  // iterators that can be reversed fold from the right without collecting
+  ($t:ident, $it:ty, rev) => {
+    impl<A> Foldable for $t<A> {
+      type Iter<'a> = $it where A: 'a;
+      fn iter(&self) -> Self::Iter<'_> {
+        self.into_iter()
+      }
+      fn fold_right<B, F>(&self, z: B, mut f: F) -> B where F: FnMut(&A, B) -> B {
+        self.into_iter().rev().fold(z, |b, a| f(a, b))
+      }
+    }
+  };
 }
 
-impl <'r,A: 'r> FoldableA<'r, A> for Vec<A> {
-  fn fold<F>(&'r self, z: A, f: F) -> A where F: FnMut(A, &A) -> A {
-    self.iter().fold(z, f)
-  }
-  fn all<F>(&'r self, f: F) -> bool where F: Fn(&A) -> bool {
-    self.iter().all(f)
-  }
-  fn any<F>(&'r self, f: F) -> bool where F: Fn(&A) -> bool {
-    self.iter().any(f)
+foldable!(Vec, std::slice::Iter<'a, A>, rev);
+foldable!(VecDeque, vec_deque::Iter<'a, A>, rev);
+foldable!(LinkedList, linked_list::Iter<'a, A>, rev);
+foldable!(BTreeSet, btree_set::Iter<'a, A>, rev);
+foldable!(HashSet, hash_set::Iter<'a, A>);
+foldable!(BinaryHeap, binary_heap::Iter<'a, A>);
+foldable!(Option, std::option::Iter<'a, A>, rev);
+
+impl<A, E> Foldable for Result<A, E> {
+  type Iter<'a> = std::result::Iter<'a, A> where A: 'a, E: 'a;
+  fn iter(&self) -> Self::Iter<'_> {
+    Result::iter(self)
   }
-  fn filter<F>(&'r self, f: F) -> Self::M where F: Fn(&A) -> bool {
-    self.iter().filter(|a| f(a)).collect()
+}
+
+impl<A> Foldable for Box<A> {
+  type Iter<'a> = std::iter::Once<&'a A> where A: 'a;
+  fn iter(&self) -> Self::Iter<'_> {
+    std::iter::once(self)
   }
-  fn find<F>(&'r self, f: F) -> Option<&A> where F: Fn(&A) -> bool {
-    self.iter().find(|a| f(a))
+}
+
+impl<A> Foldable for Rc<A> {
+  type Iter<'a> = std::iter::Once<&'a A> where A: 'a;
+  fn iter(&self) -> Self::Iter<'_> {
+    std::iter::once(self)
   }
-  fn is_empty(&'r self) -> bool { self.is_empty() }
 }
 
-impl <'r,A: 'r> FoldableS<'r, A> for Option<A> {
-  fn fold<F>(&'r self, z: A, f: F) -> A where F: Fn(&A) -> A {
-    match self {
-      Some(v) => f(v),
-      None => z,
+macro_rules! filterable {
+  ($t:ident $(, $($b:tt)+)?) => {
+    impl<A $(: $($b)+)?> Filterable for $t<A> {
+      fn filter<F>(&self, mut f: F) -> $t<&A> where F: FnMut(&A) -> bool {
+        Foldable::iter(self).filter(|a| f(a)).collect()
+      }
     }
   }
-  fn all<F>(&'r self, f: F) -> bool where F: Fn(&A) -> bool {
-    self.iter().all(f)
-  }
-  fn any<F>(&'r self, f: F) -> bool where F: Fn(&A) -> bool {
-    self.iter().any(f)
+}
+
+filterable!(Vec);
+filterable!(VecDeque);
+filterable!(LinkedList);
+filterable!(BTreeSet, Ord);
+filterable!(HashSet, Hash + Eq);
+
+impl<A> Filterable for Option<A> {
+  fn filter<F>(&self, f: F) -> Option<&A> where F: FnMut(&A) -> bool {
+    Foldable::find(self, f)
   }
-  fn filter<F>(&'r self, f: F) -> Self::M where F: Fn(&A) -> bool {
-    self.iter().find(|a| f(a))
+}
+
+impl<I: Iterator> FoldableIterator for I {
+  fn fold_map<B, F>(self, mut f: F) -> B where F: FnMut(Self::Item) -> B, B: Monoid {
+    self.fold(B::mempty(), |b, a| b.mappend(&f(a)))
   }
-  fn find<F>(&'r self, f: F) -> Option<&A> where F: Fn(&A) -> bool {
-    self.iter().find(|a| f(a))
+  fn concat(self) -> Self::Item where Self::Item: Monoid {
+    self.fold(Self::Item::mempty(), |b, a| b.mappend(&a))
   }
-  fn is_empty(&'r self) -> bool { self.is_none() }
 }
 
 #[cfg(test)]
 mod test {
   use crate::Foldable::*;
+  use crate::semigroup::{KahanSum, Max, Sum};
+  use std::collections::{BTreeSet, HashSet, VecDeque};
+
+  #[cfg(test)]
+  mod laws {
+    use crate::{Monoid, Semigroup};
+    use crate::Foldable::*;
+    use crate::nonempty::NonEmpty;
+    use crate::semigroup::Endo;
+    use crate::validation::Validation;
+    use std::collections::VecDeque;
+    use std::rc::Rc;
+
+    fn f(a: &i32) -> Vec<i32> { vec![*a, a * 10] }
+    fn step(a: &i32, b: i32) -> i32 { b * 10 + a }
+
+    macro_rules! laws {
+      ($n: ident, $v: expr) => {
+        #[test]
+        fn $n() {
+          let v = $v;
+          assert_eq!(v.fold_map(f), v.fold_right(Vec::mempty(), |a, b| f(a).mappend(&b)));
+          assert_eq!(v.fold_map(f), v.fold_left(Vec::mempty(), |b, a| b.mappend(&f(a))));
+          assert_eq!(v.fold_right(7, step), v.fold_map(|&a| Endo::new(move |b| step(&a, b))).run(7));
+          assert_eq!(v.fold_left(vec![], |mut b, a| { b.push(*a); b }), v.iter().cloned().collect::<Vec<_>>());
+        }
+      };
+    }
+    laws!(vec, vec![1, 2, 3]);
+    laws!(vec_empty, Vec::<i32>::new());
+    laws!(vec_deque, VecDeque::from(vec![1, 2, 3]));
+    laws!(option_some, Some(3));
+    laws!(option_none, None::<i32>);
+    laws!(result_ok, Ok::<i32, String>(3));
+    laws!(result_err, Err::<i32, String>("e".to_string()));
+    laws!(box_, Box::new(3));
+    laws!(rc, Rc::new(3));
+    laws!(nonempty, NonEmpty { head: 1, tail: vec![2, 3] });
+    laws!(validation, Validation::<String, i32>::Success(3));
+  }
+
+  fn total<F: Foldable<A = i32>>(fa: &F) -> i32 {
+    fa.fold(100, |b, a| a + b)
+  }
+
+  #[test]
+  fn generic() {
+    assert_eq!(106, total(&vec![1, 2, 3]));
+    assert_eq!(103, total(&Some(3)));
+    assert_eq!(100, total(&None));
+    assert_eq!(103, total(&Box::new(3)));
+  }
+
+  #[test]
+  fn option_fold_uses_z() {
+    assert_eq!(10, Some(3).fold(7, |b, a| a + b));
+    assert_eq!(7, None.fold(7, |b, a: &i32| a + b));
+  }
 
   #[test]
   fn vec_filter() {
@@ -92,10 +184,121 @@ mod test {
     assert_eq!(6, v1.filter(|&a| a%2==0).fold_right(0, |&a,b| a + b));
   }
 
+  #[test]
+  fn result_fold_left() {
+    let ok: Result<i32, &str> = Ok(3);
+    let err: Result<i32, &str> = Err("nope");
+    assert_eq!(5, ok.fold_left(2, |b, &a| a + b));
+    assert_eq!(2, err.fold_left(2, |b, &a| a + b));
+  }
+
+  #[test]
+  fn result_fold_right() {
+    let ok: Result<i32, &str> = Ok(3);
+    let err: Result<i32, &str> = Err("nope");
+    assert_eq!(1, ok.fold_right(2, |&a, b| a - b));
+    assert_eq!(2, err.fold_right(2, |&a, b| a - b));
+  }
+
+  #[test]
+  fn result_fold_map() {
+    let ok: Result<i32, &str> = Ok(3);
+    let err: Result<i32, &str> = Err("nope");
+    assert_eq!(Some(6), ok.fold_map(|&a| Some(a * 2)));
+    assert_eq!(None, err.fold_map(|&a| Some(a * 2)));
+  }
+
   #[test]
   fn vec_concat() {
     let v1 = vec![(0.1f64 * 1000f64),(0.2f64 * 1000f64)];
     assert_eq!(0.3f64, (v1.concat() / 1000f64));
   }
 
+  #[test]
+  fn vec_compensated_concat() {
+    let v1 = vec![KahanSum::new(1e16f64), KahanSum::new(1.0f64), KahanSum::new(-1e16f64)];
+    assert_eq!(1f64, v1.concat().value());
+    let v2 = vec![0.1f64; 1_000_000];
+    assert_eq!(100_000f64, v2.fold_map(|&a| KahanSum::new(a)).value());
+    assert_ne!(100_000f64, v2.concat());
+  }
+
+  #[test]
+  fn collections() {
+    let d: VecDeque<i32> = vec![1, 2, 3].into();
+    assert_eq!(vec![3, 2, 1], d.fold_right(vec![], |&a, mut b: Vec<i32>| { b.push(a); b }));
+    assert_eq!(Sum(6), d.fold_map(|&a| Sum(a)));
+    assert_eq!(VecDeque::from(vec![&2]), d.filter(|&a| a == 2));
+    let h: HashSet<i32> = vec![1, 2, 3].into_iter().collect();
+    assert_eq!(Max(3), h.fold_map(|&a| Max(a)));
+    assert!(h.all(|&a| a > 0));
+    assert_eq!(Some(&2), h.find(|&a| a == 2));
+    let b: BTreeSet<i32> = vec![3, 1, 2].into_iter().collect();
+    assert_eq!(123, b.fold_left(0, |acc, &a| acc * 10 + a));
+    assert!(BTreeSet::<i32>::new().is_empty());
+  }
+
+  #[test]
+  fn option_fold_map() {
+    assert_eq!(Sum(2), Some(2).fold_map(|&a| Sum(a)));
+    assert_eq!(Sum(0), None.fold_map(|&a: &i32| Sum(a)));
+  }
+
+  #[test]
+  fn iterators() {
+    assert_eq!(Sum(10), (1..5).fold_map(Sum));
+    assert_eq!(Max(4), [3, 1, 4].iter().fold_map(|&a| Max(a)));
+    assert_eq!(6, vec![1, 2, 3].into_iter().concat());
+    assert_eq!("ab", ["a", "b"].iter().map(|s| s.to_string()).concat());
+  }
+
+  #[test]
+  fn helpers() {
+    let n = crate::nonempty::NonEmpty { head: 3, tail: vec![1, 4, 1, 5] };
+    assert_eq!(5, n.length());
+    assert_eq!(14, n.sum());
+    assert_eq!(60, n.product());
+    assert_eq!(2, n.count(|&a| a == 1));
+    assert!(n.elem(&4));
+    assert_eq!(vec![3, 1, 4, 1, 5], n.to_vec());
+    assert_eq!(Some(&5), n.maximum_by(|a, b| a.cmp(b)));
+    assert_eq!(Some(&1), n.minimum_by(|a, b| a.cmp(b)));
+    assert_eq!(Some(31415), n.fold_left1(|b, a| b * 10 + a));
+    assert_eq!(Some(51413), n.fold_right1(|a, b| b * 10 + a));
+    assert_eq!((vec![&3, &4, &5], vec![&1, &1]), n.partition(|&a| a > 2));
+    let h: HashSet<u8> = vec![1, 2, 3].into_iter().collect();
+    assert_eq!((3, 6u8, 6u8), (h.length(), h.sum(), h.product()));
+    assert_eq!(None, None::<i32>.fold_left1(|b, a| b + a));
+    assert_eq!(2.5, Some(2.5).sum());
+  }
+
+  #[test]
+  fn short_circuit() {
+    use crate::semigroup::All;
+    use crate::Semigroup;
+    use std::cell::Cell;
+    use std::ops::ControlFlow::*;
+    let visited = Cell::new(0);
+    let v = vec![1, -2, 3, 4];
+    let r = v.fold_while(All(true), |b, &a| {
+      visited.set(visited.get() + 1);
+      let b = b.mappend(&All(a > 0));
+      if b.0 { Continue(b) } else { Break(b) }
+    });
+    assert_eq!((All(false), 2), (r, visited.get()));
+    visited.set(0);
+    let r: Result<i32, i32> = v.try_fold_left(0, |b, &a| { visited.set(visited.get() + 1); if a < 0 { Err(a) } else { Ok(b + a) } });
+    assert_eq!((Err(-2), 2), (r, visited.get()));
+    assert_eq!(Ok::<i32, ()>(6), Some(6).try_fold_left(0, |b, &a| Ok(b + a)));
+  }
+
+  #[test]
+  fn fold_m() {
+    let safe_sub = |b: &u32, a: &u32| b.checked_sub(*a);
+    assert_eq!(Some(4), vec![1, 2, 3].fold_m(10, safe_sub));
+    assert_eq!(None, vec![1, 20, 3].fold_m(10, safe_sub));
+    assert_eq!(Some(10), Vec::<u32>::new().fold_m(10, safe_sub));
+    let r: Result<u32, String> = Some(3).fold_m(1, |b, a| if *a > 0 { Ok(b * a) } else { Err("zero".to_string()) });
+    assert_eq!(Ok(3), r);
+  }
 }
@@ -7,33 +7,63 @@ use std::vec::Vec;
 use std::boxed::Box;
 use std::rc::Rc;
 
-impl<A,B> Functor<B> for Option<A> {
-  fn fmap<F>(&self, f: F) -> Option<B> where F: Fn(&A) -> B {
-    match *self{
-      Some(ref a) => Some(f(a)),
-      None => None,
-    }
+impl<A> Functor for Option<A> {
+  fn fmap<B, F>(&self, f: F) -> Option<B> where F: Fn(&A) -> B {
+    self.as_ref().map(f)
   }
 }
 
-impl<A,B> Functor<B> for Box<A> {
-  fn fmap<F>(&self, f: F) -> Box<B> where F: Fn(&A) -> B {
+impl<A> Functor for Box<A> {
+  fn fmap<B, F>(&self, f: F) -> Box<B> where F: Fn(&A) -> B {
     Box::new(f(self))
   }
 }
 
-impl<A,B> Functor<B> for Rc<A> {
-  fn fmap<F>(&self, f: F) -> Rc<B> where F: Fn(&A) -> B {
+impl<A> Functor for Rc<A> {
+  fn fmap<B, F>(&self, f: F) -> Rc<B> where F: Fn(&A) -> B {
     Rc::new(f(self))
   }
 }
 
-impl<A,B> Functor<B> for Vec<A> {
-  fn fmap<F>(&self, f: F) -> Vec<B> where F: Fn(&A) -> B {
+impl<A> Functor for Vec<A> {
+  fn fmap<B, F>(&self, f: F) -> Vec<B> where F: Fn(&A) -> B {
     self.iter().map(f).collect()
   }
 }
 
+impl<A,E: Clone> Functor for Result<A, E> {
+  fn fmap<B, F>(&self, f: F) -> Result<B, E> where F: Fn(&A) -> B {
+    match *self {
+      Ok(ref a) => Ok(f(a)),
+      Err(ref e) => Err(e.clone()),
+    }
+  }
+}
+
+/// Tuples map over their last element, the other elements are cloned
+macro_rules! functor_tuple {
+  ($($x:ident $i:tt),*; $a:ident $ai:tt) => {
+    impl<$($x: Clone,)* $a> Functor for ($($x,)* $a,) {
+      fn fmap<B, F>(&self, f: F) -> ($($x,)* B,) where F: Fn(&$a) -> B {
+        ($(self.$i.clone(),)* f(&self.$ai),)
+      }
+    }
+  }
+}
+
+functor_tuple!(; T1 0);
+functor_tuple!(T1 0; T2 1);
+functor_tuple!(T1 0, T2 1; T3 2);
+functor_tuple!(T1 0, T2 1, T3 2; T4 3);
+functor_tuple!(T1 0, T2 1, T3 2, T4 3; T5 4);
+functor_tuple!(T1 0, T2 1, T3 2, T4 3, T5 4; T6 5);
+functor_tuple!(T1 0, T2 1, T3 2, T4 3, T5 4, T6 5; T7 6);
+functor_tuple!(T1 0, T2 1, T3 2, T4 3, T5 4, T6 5, T7 6; T8 7);
+functor_tuple!(T1 0, T2 1, T3 2, T4 3, T5 4, T6 5, T7 6, T8 7; T9 8);
+functor_tuple!(T1 0, T2 1, T3 2, T4 3, T5 4, T6 5, T7 6, T8 7, T9 8; T10 9);
+functor_tuple!(T1 0, T2 1, T3 2, T4 3, T5 4, T6 5, T7 6, T8 7, T9 8, T10 9; T11 10);
+functor_tuple!(T1 0, T2 1, T3 2, T4 3, T5 4, T6 5, T7 6, T8 7, T9 8, T10 9, T11 10; T12 11);
+
 #[cfg(test)]
 mod test {
   use crate::Functor;
@@ -61,6 +91,10 @@ mod test {
     laws!(Box, Box::new(5i32), |x| x + 2, |x| x * 5);
     laws!(Rc, Rc::new(5i32), |x| x + 2, |x| x * 5);
     laws!(Vec, vec![1,2,3,4], |x| x + 2, |x| x * 5);
+    laws!(Result, Ok::<i32, String>(5i32), |x| x + 2, |x| x * 5);
+    laws!(ResultErr, Err::<i32, String>("e".to_string()), |x| x + 2, |x| x * 5);
+    laws!(Pair, ("a".to_string(), 5i32), |x| x + 2, |x| x * 5);
+    laws!(Triple, (1u8, 'c', 5i32), |x| x + 2, |x| x * 5);
   }
 
   #[test]
@@ -97,4 +131,28 @@ mod test {
     assert_eq!(vec![2,3,4], bx);
   }
 
+  #[test]
+  fn generic() {
+    fn double_all<F: Functor<A = i32>>(fa: &F) -> F::Target<i32> { fa.fmap(|i| i * 2) }
+    assert_eq!(Some(4), double_all(&Some(2)));
+    assert_eq!(Box::new(4), double_all(&Box::new(2)));
+    assert_eq!(vec![2, 4, 6], double_all(&vec![1, 2, 3]));
+    assert_eq!(Ok::<i32, ()>(4), double_all(&Ok(2)));
+  }
+
+  #[test]
+  fn result() {
+    let ok: Result<i32, &str> = Ok(1);
+    let err: Result<i32, &str> = Err("nope");
+    assert_eq!(Ok(2), ok.fmap(|x| x + 1));
+    assert_eq!(Err("nope"), err.fmap(|x| x + 1));
+  }
+
+  #[test]
+  fn tuple() {
+    assert_eq!((2,), (1,).fmap(|x| x + 1));
+    assert_eq!(("count", 2), ("count", 1).fmap(|x| x + 1));
+    assert_eq!((1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, "12"), (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12).fmap(|_| "12"));
+  }
+
 }
@@ -0,0 +1,94 @@
+//!
+//! Identity implementations and tests
+//!
+
+use crate::{Applicative, Comonad, Functor, Kind, Monad};
+
+hkt!(Identity);
+
+/// A value without any context, the simplest Monad and Comonad
+///
+/// # Examples
+///
+/// ```
+/// use funlib::{Comonad, Monad};
+/// use funlib::identity::Identity;
+/// let i = Identity(2).bind(|a| Identity(a * 10));
+/// assert_eq!(20, i.extract());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Identity<A>(pub A);
+
+impl<A> Functor for Identity<A> {
+  fn fmap<B, F>(&self, f: F) -> Identity<B> where F: Fn(&A) -> B {
+    Identity(f(&self.0))
+  }
+}
+
+impl<A> Applicative for Identity<A> {
+  fn pure_(a: A) -> Self {
+    Identity(a)
+  }
+
+  fn ap<B, F>(&self, of: Identity<F>) -> Identity<B> where F: Fn(&A) -> B {
+    Identity((of.0)(&self.0))
+  }
+}
+
+impl<A> Monad for Identity<A> {
+  fn bind<B, F>(&self, mut f: F) -> Identity<B> where F: FnMut(&A) -> Identity<B> {
+    f(&self.0)
+  }
+}
+
+impl<A: Clone> Comonad for Identity<A> {
+  fn extract(&self) -> A {
+    self.0.clone()
+  }
+
+  fn extend<B: 'static, F>(&self, f: F) -> Identity<B> where F: Fn(&Self) -> B + 'static {
+    Identity(f(self))
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::{Comonad, Functor, Monad};
+  use super::Identity;
+
+  #[cfg(test)]
+  mod laws {
+    use crate::{Applicative, Comonad, Functor, Monad};
+    use super::Identity;
+
+    fn f(a: &i32) -> Identity<i32> { Identity(a * 10) }
+    fn g(a: &i32) -> Identity<i32> { Identity(a + 1) }
+
+    #[test]
+    fn functor_applicative_monad() {
+      let i = Identity(3);
+      assert_eq!(i, i.fmap(|x| *x));
+      assert_eq!(i, i.ap(Identity::<fn(&i32) -> i32>::pure_(|x| *x)));
+      assert_eq!(f(&4), Identity::pure_(4).bind(f));
+      assert_eq!(i, i.bind(|a| Identity::pure_(*a)));
+      assert_eq!(i.bind(f).bind(g), i.bind(|a| f(a).bind(g)));
+    }
+
+    #[test]
+    fn comonad() {
+      let i = Identity(3);
+      let (f, g) = (|w: &Identity<i32>| w.0 * 10, |w: &Identity<i32>| w.0 + 1);
+      assert_eq!(i, i.extend(|w| w.extract()));
+      assert_eq!(f(&i), i.extend(f).extract());
+      assert_eq!(i.extend(g).extend(f), i.extend(move |w| f(&w.extend(g))));
+    }
+  }
+
+  #[test]
+  fn identity() {
+    assert_eq!(Identity(4), Identity(2).fmap(|a| a * 2));
+    assert_eq!(Identity("2"), Identity(2).bind(|_| Identity("2")));
+    assert_eq!(Identity(Identity(2)), Identity(2).duplicate());
+    assert_eq!(2, Identity(2).extract());
+  }
+}
@@ -20,19 +20,70 @@
 //!
 //! ```
 //!
+//! Do notation
+//!
+//! ```
+//! use funlib::mdo;
+//!
+//! let s = mdo! {
+//!   a <- Some(3);
+//!   b <- Some(a * 2);
+//!   pure a + b
+//! }; // this will produce Some(9)
+//! assert_eq!(Some(9), s);
+//! ```
+//!
 //!
 //! 
 #![deny(missing_docs)]
 #[macro_use] extern crate funlib_macros;
+extern crate self as funlib;
+pub use funlib_macros::mdo;
+pub use funlib_macros::map_n;
 pub mod functor;
+pub mod bifunctor;
+pub mod contravariant;
+pub mod profunctor;
 pub mod applicative;
 pub mod monad;
 pub mod semigroup;
 pub mod monoid;
 pub mod foldable;
+pub mod traversable;
+pub mod alternative;
+pub mod nonempty;
+pub mod validation;
+pub mod ziplist;
+pub mod identity;
+pub mod env;
+pub mod store;
+pub mod reader;
+pub mod writer;
+use std::collections::{BTreeSet, BinaryHeap, HashSet, LinkedList, VecDeque};
 use std::rc::Rc;
 
+/// Higher Kinded Type helper using generic associated types, M<A> -> M<B>
+///
+/// Lets code be generic over any type constructor:
+///
+/// ```
+/// use funlib::{Functor, Kind};
+/// fn double_all<F: Functor<A = i32>>(fa: &F) -> F::Target<i32> {
+///   fa.fmap(|i| i * 2)
+/// }
+/// assert_eq!(Some(4), double_all(&Some(2)));
+/// assert_eq!(vec![2, 4], double_all(&vec![1, 2]));
+/// ```
+pub trait Kind {
+  /// Current Type
+  type A;
+  /// Type M<B>
+  type Target<B>;
+}
+
 /// Higher Kinded Type helper for M<A> -> M<B>
+///
+/// Kept for compatibility, implemented for every type that implements `Kind`
 pub trait HKT<B> {
   /// Current Type
   type A;
@@ -40,6 +91,8 @@ pub trait HKT<B> {
   type M;
 }
 /// Higher kinded Type helper for M<A> -> M<A>
+///
+/// Kept for compatibility, implemented for every type that implements `Kind`
 pub trait HKST<'a, B> {
   /// Current Type
   type A;
@@ -47,13 +100,78 @@ pub trait HKST<'a, B> {
   type M;
 }
 
+impl<T: Kind, B> HKT<B> for T {
+  type A = T::A;
+  type M = T::Target<B>;
+}
+
+impl<'a, T: Kind> HKST<'a, T::A> for T where T::A: 'a {
+  type A = &'a T::A;
+  type M = T::Target<&'a T::A>;
+}
+
+/// Higher Kinded Type helper for types with two parameters, M<A, B> -> M<C, D>
+///
+/// Registered with `hkt!(Result<_, _>)`, see `Bifunctor`
+pub trait Kind2 {
+  /// First type
+  type A;
+  /// Second type
+  type B;
+  /// Type M<C, D>
+  type Target<C, D>;
+}
+
 hkt!(Vec);
 hkt!(Option);
 hkt!(Box);
 hkt!(Rc);
+hkt!(Result<_, E>);
+hkt!(VecDeque);
+hkt!(LinkedList);
+hkt!(HashSet);
+hkt!(BTreeSet);
+hkt!(BinaryHeap);
+hkt!(Result<_, _>);
+
+impl<T1, T2> Kind2 for (T1, T2) {
+  type A = T1;
+  type B = T2;
+  type Target<C, D> = (C, D);
+}
+
+/// Boxed functions map their input and output types, see `Profunctor`
+impl<A, R> Kind2 for Box<dyn Fn(A) -> R> {
+  type A = A;
+  type B = R;
+  type Target<C, D> = Box<dyn Fn(C) -> D>;
+}
+
+/// Tuples are mapped over their last element
+macro_rules! tuple_kind {
+  ($($x:ident),*; $a:ident) => {
+    impl<$($x,)* $a> Kind for ($($x,)* $a,) {
+      type A = $a;
+      type Target<B> = ($($x,)* B,);
+    }
+  }
+}
+
+tuple_kind!(; T1);
+tuple_kind!(T1; T2);
+tuple_kind!(T1, T2; T3);
+tuple_kind!(T1, T2, T3; T4);
+tuple_kind!(T1, T2, T3, T4; T5);
+tuple_kind!(T1, T2, T3, T4, T5; T6);
+tuple_kind!(T1, T2, T3, T4, T5, T6; T7);
+tuple_kind!(T1, T2, T3, T4, T5, T6, T7; T8);
+tuple_kind!(T1, T2, T3, T4, T5, T6, T7, T8; T9);
+tuple_kind!(T1, T2, T3, T4, T5, T6, T7, T8, T9; T10);
+tuple_kind!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10; T11);
+tuple_kind!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11; T12);
 
 /// Functor type class
-pub trait Functor<B>: HKT<B> {
+pub trait Functor: Kind {
   /// Functor map
   /// # Examples
   /// ```
@@ -61,11 +179,171 @@ pub trait Functor<B>: HKT<B> {
   /// let n = Some(1).fmap(|i| i * 4);
   /// assert_eq!(Some(4), n);
   /// ```
-  fn fmap<F>(&self, f: F) -> Self::M where F: Fn(&Self::A) -> B;
+  fn fmap<B, F>(&self, f: F) -> Self::Target<B> where F: Fn(&Self::A) -> B;
+}
+
+/// Bifunctor type class, a Functor over both parameters of a two parameter type
+///
+/// `first` maps the first type parameter and `second` the second one, so for
+/// `Result<A, E>` `first` maps the `Ok` value and `second` the `Err` value.
+pub trait Bifunctor: Kind2 {
+  /// Map both sides at once
+  ///
+  /// # Examples
+  /// ```
+  /// use funlib::Bifunctor;
+  /// assert_eq!(("1".to_string(), 4), (1, 2).bimap(|a| a.to_string(), |b| b * 2));
+  /// let r: Result<i32, String> = Err("e".to_string());
+  /// assert_eq!(Err(1), r.bimap(|a| a + 1, |e| e.len()));
+  /// ```
+  fn bimap<C, D, F, G>(&self, f: F, g: G) -> Self::Target<C, D> where F: Fn(&Self::A) -> C, G: Fn(&Self::B) -> D;
+  /// Map the first side, the second side is cloned
+  ///
+  /// # Examples
+  /// ```
+  /// use funlib::Bifunctor;
+  /// assert_eq!((2, "b"), (1, "b").first(|a| a + 1));
+  /// assert_eq!(Ok::<i32, &str>(2), Ok(1).first(|a| a + 1));
+  /// ```
+  fn first<C, F>(&self, f: F) -> Self::Target<C, Self::B> where F: Fn(&Self::A) -> C, Self::B: Clone {
+    self.bimap(f, |b| b.clone())
+  }
+  /// Map the second side, the first side is cloned
+  ///
+  /// # Examples
+  /// ```
+  /// use funlib::Bifunctor;
+  /// assert_eq!((1, 3), (1, 2).second(|b| b + 1));
+  /// assert_eq!(Err::<i32, usize>(1), Err("e").second(|e| e.len()));
+  /// ```
+  fn second<D, G>(&self, g: G) -> Self::Target<Self::A, D> where G: Fn(&Self::B) -> D, Self::A: Clone {
+    self.bimap(|a| a.clone(), g)
+  }
+}
+
+/// Contravariant type class, for types that consume values like predicates and comparators
+///
+/// The value is consumed, wrap it in a cloneable type like `Predicate` to reuse it.
+pub trait Contravariant: Kind {
+  /// Contravariant map, turns a consumer of A into a consumer of B by converting each B first
+  ///
+  /// # Examples
+  /// ```
+  /// use funlib::Contravariant;
+  /// use funlib::contravariant::Predicate;
+  /// let even = Predicate::new(|i: &i32| i % 2 == 0);
+  /// let even_length = even.contramap(|s: &&str| s.len() as i32);
+  /// assert!(even_length.test(&"ab"));
+  /// assert!(!even_length.test(&"abc"));
+  /// ```
+  fn contramap<B: 'static, F>(self, f: F) -> Self::Target<B> where F: Fn(&B) -> Self::A + 'static;
+}
+
+/// Profunctor type class, for function like types that consume the first type and produce the second
+///
+/// The value is consumed, like function composition.
+pub trait Profunctor: Kind2 {
+  /// Map the input with f and the output with g
+  ///
+  /// # Examples
+  /// ```
+  /// use funlib::Profunctor;
+  /// let len: Box<dyn Fn(String) -> usize> = Box::new(|s: String| s.len());
+  /// let f = len.dimap(|i: i32| i.to_string(), |n| n * 10);
+  /// assert_eq!(30, f(100));
+  /// ```
+  fn dimap<C: 'static, D: 'static, F, G>(self, f: F, g: G) -> Self::Target<C, D>
+    where F: Fn(C) -> Self::A + 'static, G: Fn(Self::B) -> D + 'static;
+  /// Map the input only, the `Contravariant` map of function like types
+  ///
+  /// # Examples
+  /// ```
+  /// use funlib::Profunctor;
+  /// let double: Box<dyn Fn(i32) -> i32> = Box::new(|i| i * 2);
+  /// assert_eq!(6, double.lmap(|s: &str| s.len() as i32)("abc"));
+  /// ```
+  fn lmap<C: 'static, F>(self, f: F) -> Self::Target<C, Self::B> where F: Fn(C) -> Self::A + 'static, Self: Sized, Self::B: 'static {
+    self.dimap(f, |b| b)
+  }
+  /// Map the output only
+  ///
+  /// # Examples
+  /// ```
+  /// use funlib::Profunctor;
+  /// let double: Box<dyn Fn(i32) -> i32> = Box::new(|i| i * 2);
+  /// assert_eq!("6", double.rmap(|i| i.to_string())(3));
+  /// ```
+  fn rmap<D: 'static, G>(self, g: G) -> Self::Target<Self::A, D> where G: Fn(Self::B) -> D + 'static, Self: Sized, Self::A: 'static {
+    self.dimap(|a| a, g)
+  }
+}
+
+/// Generates `map3` up to `map12` of Applicative from the previous arity
+///
+/// Every `mapN` applies self to the Applicative built by `map(N-1)` on the second
+/// Applicative, so the effects still run from left to right. For `map3` that is
+///
+/// ```text
+/// fn map3<'a, B, C, R, Func>(&self, fb: &Self::Target<B>, fc: &Self::Target<C>, func: Func) -> Self::Target<R>
+///   where Func: Fn(&Self::A, &B, &C) -> R + 'a, B: Clone + 'a, C: Clone + 'a,
+///         Self::Target<B>: Applicative<A = B> + Kind<Target<C> = Self::Target<C>>
+///           + Kind<Target<S1> = Self::Target<S1>> + Kind<Target<S2> = Self::Target<S2>>,
+///         Self::Target<C>: Functor<A = C> + Kind<Target<S2> = Self::Target<S2>> {
+///   let rest = fb.map2(fc, |b, c| -> S1 { Box::new(move |a| func(a, &b, &c)) });
+///   self.ap::<R, S1>(rest)
+/// }
+/// ```
+///
+/// where `S1 = ApStep<'a, Self::A, R>` is the step applied to self and
+/// `S2 = ApStep<'a, B, S1>` the one `map2` applies to `fb`. Every extra Applicative
+/// adds the next step `S(k+1) = ApStep<'a, Tk, Sk>`, and each `Self::Target<Tk>`
+/// has to agree with `Self::Target` on the later types and steps, so that the bounds
+/// of the previous arity hold for it.
+macro_rules! applicative_map {
+  // `@next` keeps the previous method, the Applicatives after self as `Type arg value`,
+  // the steps `S1..Sm` and the last Applicative and step to build the next step from
+  (@next $prev:ident [$($t:ident $f:ident $v:ident),+] [$($s:ty),+] $lt:ident $ls:ty;) => {};
+  (@next $prev:ident [$($t:ident $f:ident $v:ident),+] [$($s:ty),+] $lt:ident $ls:ty;
+    $(,)? $name:ident $n:literal $nt:ident $nf:ident $nv:ident $($rest:tt)*) => {
+    applicative_map!(@bounds {$name $n $prev [$($t $f $v,)+ $nt $nf $nv]} [$($t)+ $nt] [$($s,)+ ApStep<'a, $lt, $ls>] []);
+    applicative_map!(@next $name [$($t $f $v,)+ $nt $nf $nv] [$($s,)+ ApStep<'a, $lt, $ls>] $nt ApStep<'a, $lt, $ls>; $($rest)*);
+  };
+  // `@bounds` walks the Applicatives and their steps together, the last one only has to be a Functor
+  (@bounds {$($method:tt)+} [$t:ident] [$s:ty] [$($acc:tt)*]) => {
+    applicative_map!(@method $($method)+ [$($acc)*
+      Self::Target<$t>: Functor<A = $t> + Kind<Target<$s> = Self::Target<$s>>,
+    ]);
+  };
+  (@bounds {$($method:tt)+} [$t:ident $($ts:ident)+] [$s:ty $(, $ss:ty)+] [$($acc:tt)*]) => {
+    applicative_map!(@bounds {$($method)+} [$($ts)+] [$($ss),+] [$($acc)*
+      Self::Target<$t>: Applicative<A = $t> $(+ Kind<Target<$ts> = Self::Target<$ts>>)+
+        + Kind<Target<$s> = Self::Target<$s>> $(+ Kind<Target<$ss> = Self::Target<$ss>>)+,
+    ]);
+  };
+  // `@method` writes out the method itself
+  (@method $name:ident $n:literal $prev:ident [$b:ident $fb:ident $vb:ident, $($t:ident $f:ident $v:ident),+] [$($bounds:tt)+]) => {
+    #[doc = concat!("Combines ", stringify!($n), " Applicatives with func, see `map2`")]
+    #[allow(clippy::too_many_arguments)]
+    fn $name<'a, $b, $($t,)+ R, Func>(&self, $fb: &Self::Target<$b>, $($f: &Self::Target<$t>,)+ func: Func) -> Self::Target<R>
+      where Func: Fn(&Self::A, &$b, $(&$t),+) -> R + 'a,
+            $b: Clone + 'a, $($t: Clone + 'a,)+
+            $($bounds)+ {
+      let func = Rc::new(func);
+      let rest = $fb.$prev($($f,)+ move |$vb: &$b, $($v: &$t),+| {
+        let (func, $vb, $($v),+) = (func.clone(), $vb.clone(), $($v.clone()),+);
+        Box::new(move |a: &Self::A| func(a, &$vb, $(&$v),+)) as ApStep<'a, Self::A, R>
+      });
+      self.ap::<R, ApStep<'a, Self::A, R>>(rest)
+    }
+  };
+  // start from `map2`, which is written out in the trait
+  ($($arities:tt)+) => {
+    applicative_map!(@next map2 [B fb b] [ApStep<'a, Self::A, R>] B ApStep<'a, Self::A, R>; $($arities)+);
+  };
 }
 
 /// Applicative type class
-pub trait Applicative<B>: Functor<B> {
+pub trait Applicative: Functor {
   /// Lift values into the context of the Functor
   ///
   /// # Examples
@@ -75,7 +353,7 @@ pub trait Applicative<B>: Functor<B> {
   /// let s2 = Option::pure_("hi");
   /// let v = Vec::pure_(1);
   /// ```
-  fn pure_(value: B) -> Self::M where Self: HKT<B, A=B>;
+  fn pure_(value: Self::A) -> Self;
   /// Apply function is almost the same as Functor map. but the function isn't A => B but A<F => B>
   ///
   /// # Examples
@@ -86,12 +364,75 @@ pub trait Applicative<B>: Functor<B> {
   /// assert_eq!(Some(4), Some(2).ap(Some(f)));
   /// assert_eq!(Some(4), Some(2).ap(Some(&double)));
   /// ```
-  fn ap<F>(&self, f: <Self as HKT<F>>::M) -> <Self as HKT<B>>::M
-      where F: Fn(&<Self as HKT<B>>::A) -> B, Self:HKT<F>;
+  fn ap<B, F>(&self, f: Self::Target<F>) -> Self::Target<B> where F: Fn(&Self::A) -> B;
+  /// Combines two Applicatives with func, the effects of self come first
+  ///
+  /// `map3` up to `map12` combine more Applicatives the same way.
+  ///
+  /// # Examples
+  /// ```
+  /// use funlib::Applicative;
+  /// assert_eq!(Some(3), Some(1).map2(&Some(2), |a, b| a + b));
+  /// assert_eq!(None, Some(1).map2(&None::<i32>, |a, b| a + b));
+  /// let r: Result<i32, String> = Ok(1).map3(&Ok(2), &Ok(3), |a, b, c| a + b * c);
+  /// assert_eq!(Ok(7), r);
+  /// ```
+  fn map2<'a, B, R, Func>(&self, fb: &Self::Target<B>, func: Func) -> Self::Target<R>
+    where Func: Fn(&Self::A, &B) -> R + 'a,
+          B: Clone + 'a,
+          Self::Target<B>: Functor<A = B> + Kind<Target<ApStep<'a, Self::A, R>> = Self::Target<ApStep<'a, Self::A, R>>> {
+    let func = Rc::new(func);
+    let c2 = fb.fmap(|b| {
+      let (func, b) = (func.clone(), b.clone());
+      Box::new(move |a: &Self::A| func(a, &b)) as ApStep<'a, Self::A, R>
+    });
+    self.ap::<R, ApStep<'a, Self::A, R>>(c2)
+  }
+  applicative_map!(map3 3 C fc c, map4 4 D fd d, map5 5 E fe e, map6 6 F ff f, map7 7 G fg g, map8 8 H fh h, map9 9 I fi i,
+    map10 10 J fj j, map11 11 K fk k, map12 12 L fl l);
+  /// Pair up the values of two Applicatives, Option's inherent `zip` needs the `Applicative::zip` path
+  ///
+  /// # Examples
+  /// ```
+  /// use funlib::Applicative;
+  /// assert_eq!(Some((1, "a")), Applicative::zip(&Some(1), &Some("a")));
+  /// assert_eq!(vec![(1, "a")], vec![1].zip(&vec!["a"]));
+  /// ```
+  fn zip<'a, B>(&self, fb: &Self::Target<B>) -> Self::Target<(Self::A, B)>
+    where Self::A: Clone + 'a, B: Clone + 'a,
+          Self::Target<B>: Functor<A = B> + Kind<Target<ApStep<'a, Self::A, (Self::A, B)>> = Self::Target<ApStep<'a, Self::A, (Self::A, B)>>> {
+    self.map2(fb, |a: &Self::A, b: &B| (a.clone(), b.clone()))
+  }
+  /// Sequence two Applicatives keeping the value of the first
+  ///
+  /// # Examples
+  /// ```
+  /// use funlib::Applicative;
+  /// assert_eq!(Some(1), Some(1).ap_first(&Some("a")));
+  /// assert_eq!(Err("e"), Ok::<i32, &str>(1).ap_first(&Err::<(), &str>("e")));
+  /// ```
+  fn ap_first<'a, B>(&self, fb: &Self::Target<B>) -> Self::Target<Self::A>
+    where Self::A: Clone + 'a, B: Clone + 'a,
+          Self::Target<B>: Functor<A = B> + Kind<Target<ApStep<'a, Self::A, Self::A>> = Self::Target<ApStep<'a, Self::A, Self::A>>> {
+    self.map2(fb, |a: &Self::A, _: &B| a.clone())
+  }
+  /// Sequence two Applicatives keeping the value of the second
+  ///
+  /// # Examples
+  /// ```
+  /// use funlib::Applicative;
+  /// assert_eq!(Some("a"), Some(1).ap_second(&Some("a")));
+  /// assert_eq!(None, None::<i32>.ap_second(&Some("a")));
+  /// ```
+  fn ap_second<'a, B>(&self, fb: &Self::Target<B>) -> Self::Target<B>
+    where Self::A: 'a, B: Clone + 'a,
+          Self::Target<B>: Functor<A = B> + Kind<Target<ApStep<'a, Self::A, B>> = Self::Target<ApStep<'a, Self::A, B>>> {
+    self.map2(fb, |_: &Self::A, b: &B| b.clone())
+  }
 }
 
 /// Monad type class
-pub trait Monad<B>: Applicative<B> {
+pub trait Monad: Applicative {
   /// Bind works like map but it flattens nested structures
   ///
   /// # Examples
@@ -104,7 +445,195 @@ pub trait Monad<B>: Applicative<B> {
   /// assert_eq!(None, a);
   /// assert_eq!(Some(6), b);
   /// ```
-  fn bind<F>(&self, f: F) -> Self::M where F: Fn(&Self::A) -> Self::M;
+  fn bind<B, F>(&self, f: F) -> Self::Target<B> where F: Fn(&Self::A) -> Self::Target<B>;
+  /// Flattens a nested Monad into a single layer
+  ///
+  /// # Examples
+  /// ```
+  /// use funlib::Monad;
+  /// assert_eq!(Some(1), Some(Some(1)).join());
+  /// assert_eq!(vec![1, 2, 3], vec![vec![1], vec![2, 3]].join());
+  /// ```
+  fn join<B>(&self) -> Self::Target<B> where Self: Kind<A = <Self as Kind>::Target<B>>, Self::Target<B>: Clone {
+    self.bind(|m| m.clone())
+  }
+  /// Runs this Monad and then the next one, keeping only the result of the next one
+  ///
+  /// # Examples
+  /// ```
+  /// use funlib::Monad;
+  /// assert_eq!(Some("b"), Some(1).then(Some("b")));
+  /// assert_eq!(None, None::<i32>.then(Some("b")));
+  /// assert_eq!(vec!['x', 'x'], vec![1, 2].then(vec!['x']));
+  /// ```
+  fn then<B>(&self, next: Self::Target<B>) -> Self::Target<B> where Self::Target<B>: Clone {
+    self.bind(|_| next.clone())
+  }
+  /// Runs the Monad n times and collects the results
+  ///
+  /// # Examples
+  /// ```
+  /// use funlib::Monad;
+  /// assert_eq!(Some(vec![1, 1, 1]), Some(1).replicate_m(3));
+  /// assert_eq!(vec![vec![0, 0], vec![0, 1], vec![1, 0], vec![1, 1]], vec![0, 1].replicate_m(2));
+  /// ```
+  fn replicate_m(&self, n: usize) -> Self::Target<Vec<Self::A>>
+    where Self::A: Clone,
+          Self::Target<Vec<Self::A>>: Monad<A = Vec<Self::A>> + Kind<Target<Vec<Self::A>> = Self::Target<Vec<Self::A>>> {
+    monad::concat_m((0..n).map(|_| self.fmap(|a| vec![a.clone()])).collect())
+  }
+  /// Runs the Monad only when the condition is true, otherwise returns `pure_(())`
+  ///
+  /// # Examples
+  /// ```
+  /// use funlib::Monad;
+  /// assert_eq!(None, Option::when(true, None));
+  /// assert_eq!(Some(()), Option::when(false, None));
+  /// ```
+  fn when(cond: bool, m: Self) -> Self where Self: Kind<A = ()> + Sized {
+    if cond { m } else { Self::pure_(()) }
+  }
+  /// Runs the Monad only when the condition is false, otherwise returns `pure_(())`
+  ///
+  /// # Examples
+  /// ```
+  /// use funlib::Monad;
+  /// assert_eq!(Some(()), Option::unless(true, None));
+  /// assert_eq!(None, Option::unless(false, None));
+  /// ```
+  fn unless(cond: bool, m: Self) -> Self where Self: Kind<A = ()> + Sized {
+    Self::when(!cond, m)
+  }
+  /// Discards the value, keeping only the structure
+  ///
+  /// # Examples
+  /// ```
+  /// use funlib::Monad;
+  /// assert_eq!(Some(()), Some(5).void());
+  /// assert_eq!(vec![(), ()], vec![1, 2].void());
+  /// ```
+  fn void(&self) -> Self::Target<()> {
+    self.fmap(|_| ())
+  }
+}
+
+/// Comonad type class, the dual of Monad
+///
+/// Where a Monad builds a context around values a Comonad reads values out of its
+/// context, and `extend` runs a computation at every position of that context.
+pub trait Comonad: Kind {
+  /// The value at the current position
+  ///
+  /// # Examples
+  /// ```
+  /// use funlib::Comonad;
+  /// use funlib::nonempty::NonEmpty;
+  /// assert_eq!(1, NonEmpty { head: 1, tail: vec![2, 3] }.extract());
+  /// ```
+  fn extract(&self) -> Self::A;
+  /// Run f at every position, each call sees the whole context focused on its position
+  ///
+  /// # Examples
+  /// ```
+  /// use funlib::Comonad;
+  /// use funlib::nonempty::NonEmpty;
+  /// let n = NonEmpty { head: 1, tail: vec![2, 3] };
+  /// assert_eq!(NonEmpty { head: 6, tail: vec![5, 3] }, n.extend(|w| w.iter().sum::<i32>()));
+  /// ```
+  fn extend<B: 'static, F>(&self, f: F) -> Self::Target<B> where F: Fn(&Self) -> B + 'static;
+  /// The context focused on every position
+  ///
+  /// # Examples
+  /// ```
+  /// use funlib::Comonad;
+  /// use funlib::nonempty::NonEmpty;
+  /// let n = NonEmpty { head: 1, tail: vec![2] };
+  /// assert_eq!(NonEmpty { head: n.clone(), tail: vec![NonEmpty::new(2)] }, n.duplicate());
+  /// ```
+  fn duplicate(&self) -> Self::Target<Self> where Self: Clone + 'static {
+    self.extend(|w: &Self| w.clone())
+  }
+}
+
+/// Boxed step used by Traversable to rebuild a structure of type T inside an Applicative
+pub type TraverseStep<'a, T> = Box<dyn Fn(&T) -> T + 'a>;
+
+/// Boxed function step used by the `mapN` methods of Applicative
+pub type ApStep<'a, A, B> = Box<dyn Fn(&A) -> B + 'a>;
+
+/// Traversable type class
+///
+/// `GB` is the Applicative returned by the traversing function (G<B>), the
+/// result is that Applicative holding the rebuilt structure (G<M<B>>).
+pub trait Traversable: Functor {
+  /// Map every value to an Applicative and collect the results inside a single Applicative
+  ///
+  /// # Examples
+  /// ```
+  /// use funlib::Traversable;
+  /// fn positive(i: &i32) -> Option<i32> { if *i > 0 { Some(*i) } else { None } }
+  /// assert_eq!(Some(vec![1, 2, 3]), vec![1, 2, 3].traverse(positive));
+  /// assert_eq!(None, vec![1, -2, 3].traverse(positive));
+  /// ```
+  fn traverse<'a, B, GB, F>(&self, f: F) -> GB::Target<Self::Target<B>>
+    where F: Fn(&Self::A) -> GB,
+          B: Clone + 'a,
+          GB: Functor<A = B>,
+          GB::Target<Self::Target<B>>: Applicative<A = Self::Target<B>>
+            + Kind<Target<Self::Target<B>> = GB::Target<Self::Target<B>>>
+            + Kind<Target<TraverseStep<'a, Self::Target<B>>> = GB::Target<TraverseStep<'a, Self::Target<B>>>>;
+  /// Turn a structure of Applicatives into an Applicative of the structure
+  ///
+  /// # Examples
+  /// ```
+  /// use funlib::Traversable;
+  /// assert_eq!(Some(vec![1, 2]), vec![Some(1), Some(2)].sequence());
+  /// assert_eq!(None, vec![Some(1), None].sequence());
+  /// ```
+  fn sequence<'a, B>(&self) -> <Self::A as Kind>::Target<Self::Target<B>>
+    where B: Clone + 'a,
+          Self::A: Functor<A = B> + Clone,
+          <Self::A as Kind>::Target<Self::Target<B>>: Applicative<A = Self::Target<B>>
+            + Kind<Target<Self::Target<B>> = <Self::A as Kind>::Target<Self::Target<B>>>
+            + Kind<Target<TraverseStep<'a, Self::Target<B>>> = <Self::A as Kind>::Target<TraverseStep<'a, Self::Target<B>>>> {
+    self.traverse(|ga| ga.clone())
+  }
+  /// Traverse only for the effects of the Applicative, discarding the values
+  ///
+  /// # Examples
+  /// ```
+  /// use funlib::Traversable;
+  /// fn positive(i: &i32) -> Result<i32, String> { if *i > 0 { Ok(*i) } else { Err(format!("{} is negative", i)) } }
+  /// assert_eq!(Ok(()), vec![1, 2, 3].traverse_(positive));
+  /// assert_eq!(Err("-2 is negative".to_string()), vec![1, -2, 3].traverse_(positive));
+  /// ```
+  fn traverse_<'a, B, GB, F>(&self, f: F) -> GB::Target<()>
+    where F: Fn(&Self::A) -> GB,
+          B: Clone + 'a,
+          GB: Functor<A = B>,
+          GB::Target<Self::Target<B>>: Applicative<A = Self::Target<B>>
+            + Kind<Target<Self::Target<B>> = GB::Target<Self::Target<B>>>
+            + Kind<Target<TraverseStep<'a, Self::Target<B>>> = GB::Target<TraverseStep<'a, Self::Target<B>>>>
+            + Kind<Target<()> = GB::Target<()>> {
+    self.traverse(f).fmap(|_| ())
+  }
+  /// Run an effectful function for each value, same as `traverse_`
+  ///
+  /// # Examples
+  /// ```
+  /// use funlib::Traversable;
+  /// assert_eq!(Some(()), Some(5).for_each_m(|&i| if i > 2 { Some(i) } else { None }));
+  /// ```
+  fn for_each_m<'a, B, GB, F>(&self, f: F) -> GB::Target<()>
+    where F: Fn(&Self::A) -> GB,
+          B: Clone + 'a,
+          GB: Functor<A = B>,
+          GB::Target<Self::Target<B>>: Applicative<A = Self::Target<B>>
+            + Kind<Target<Self::Target<B>> = GB::Target<Self::Target<B>>>
+            + Kind<Target<TraverseStep<'a, Self::Target<B>>> = GB::Target<TraverseStep<'a, Self::Target<B>>>>
+            + Kind<Target<()> = GB::Target<()>> {
+    self.traverse_(f)
+  }
 }
 
 /// Semigroup type class
@@ -136,12 +665,130 @@ pub trait Monoid: Semigroup {
   fn mempty() -> Self;
 }
 
+/// SemigroupK type class, combines two values of a type constructor without looking at the inner values
+pub trait SemigroupK: Kind + Sized {
+  /// combine 2 of the same type constructor
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use funlib::SemigroupK;
+  /// assert_eq!(Some(1), Some(1).combine_k(&Some(3)));
+  /// assert_eq!(Some(3), None.combine_k(&Some(3)));
+  /// assert_eq!(vec![1, 2, 3], vec![1].combine_k(&vec![2, 3]));
+  /// ```
+  fn combine_k(&self, other: &Self) -> Self;
+}
+
+/// MonoidK type class extends the SemigroupK and adds an empty value for the type constructor
+pub trait MonoidK: SemigroupK {
+  /// empty value for any inner type
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use funlib::MonoidK;
+  /// assert_eq!(None::<i32>, Option::empty_k());
+  /// assert_eq!(Vec::<i32>::new(), Vec::empty_k());
+  /// ```
+  fn empty_k() -> Self;
+}
+
+/// Alternative type class, an Applicative that is also a MonoidK
+pub trait Alternative: Applicative + MonoidK {
+  /// The identity of `alt`
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use funlib::Alternative;
+  /// assert_eq!(None::<i32>, Option::empty());
+  /// ```
+  fn empty() -> Self { Self::empty_k() }
+  /// Choose between two alternatives
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use funlib::Alternative;
+  /// assert_eq!(Some(1), None.alt(&Some(1)).alt(&Some(2)));
+  /// assert_eq!(vec![1, 2], vec![1].alt(&vec![2]));
+  /// ```
+  fn alt(&self, other: &Self) -> Self { self.combine_k(other) }
+  /// Succeeds with `()` when the condition holds, otherwise returns `empty`
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use funlib::Alternative;
+  /// assert_eq!(Some(()), Option::guard(2 > 1));
+  /// assert_eq!(None, Option::guard(1 > 2));
+  /// ```
+  fn guard(cond: bool) -> Self where Self: Kind<A = ()> + Sized {
+    if cond { Self::pure_(()) } else { Self::empty() }
+  }
+  /// Turns a failure into a success holding None
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use funlib::Alternative;
+  /// assert_eq!(Some(Some(1)), Some(1).optional());
+  /// assert_eq!(Some(None), None::<i32>.optional());
+  /// assert_eq!(vec![Some(1), Some(2), None], vec![1, 2].optional());
+  /// ```
+  fn optional(&self) -> Self::Target<Option<Self::A>>
+    where Self::A: Clone,
+          Self::Target<Option<Self::A>>: Alternative<A = Option<Self::A>> {
+    self.fmap(|a| Some(a.clone())).alt(&Self::Target::<Option<Self::A>>::pure_(None))
+  }
+  /// Runs `f` repeatedly, collecting the results until it fails. Fails when the first run fails
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use funlib::Alternative;
+  /// let mut it = vec![1, 2, 3].into_iter();
+  /// assert_eq!(Some(vec![1, 2, 3]), Option::some(|| it.next()));
+  /// assert_eq!(None, Option::<i32>::some(|| None));
+  /// ```
+  fn some<F>(f: F) -> Self::Target<Vec<Self::A>> where F: FnMut() -> Self;
+  /// Runs `f` repeatedly, collecting the results until it fails. Always succeeds
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use funlib::Alternative;
+  /// let mut it = vec![1, 2, 3].into_iter();
+  /// assert_eq!(Some(vec![1, 2, 3]), Option::many(|| it.next()));
+  /// assert_eq!(Some(vec![]), Option::<i32>::many(|| None));
+  /// ```
+  fn many<F>(f: F) -> Self::Target<Vec<Self::A>> where F: FnMut() -> Self;
+}
+
 /// Foldable mod containing the foldable type classes
 #[allow(non_snake_case)]
 pub mod Foldable {
-  use crate::{HKST, HKT, Monoid};
-  /// FoladableA is for endo type functions
-  pub trait FoldableA<'r, A: 'r>: HKST<'r, A> {
+  use crate::{Kind, Monad, Monoid, Semigroup};
+  use crate::semigroup::{Product, Sum};
+  use std::cmp::Ordering;
+  use std::ops::ControlFlow;
+  /// Foldable type class, for data structures that can be reduced to a single value
+  ///
+  /// Every operation is provided on top of `iter`, which visits the values from left to right
+  /// by reference, so containers such as Option, Vec or Box fold in the same way.
+  pub trait Foldable: Kind {
+    /// Iterator over references to the values
+    type Iter<'a>: Iterator<Item = &'a Self::A> where Self: 'a;
+    /// Iterate over the values from left to right
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use funlib::Foldable::*;
+    /// assert_eq!(vec![&1], Foldable::iter(&Some(1)).collect::<Vec<_>>());
+    /// ```
+    fn iter(&self) -> Self::Iter<'_>;
     /// Reduces the values of the Foldable into a single value
     ///
     /// # Examples
@@ -151,8 +798,52 @@ pub mod Foldable {
     /// let v = vec![1,2,3,4];
     /// let sum = v.fold(0, |b, a| a + b);
     /// assert_eq!(10, sum);
+    /// assert_eq!(5, Some(4).fold(1, |b, a| a + b));
+    /// assert_eq!(1, None.fold(1, |b, a: &i32| a + b));
+    /// ```
+    fn fold<F>(&self, z: Self::A, f: F) -> Self::A where F: FnMut(Self::A, &Self::A) -> Self::A { self.fold_left(z, f) }
+    /// Reduces the values of the Foldable from the right into a single value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use funlib::Foldable::*;
+    /// #[derive(Debug, PartialEq)]
+    /// struct Count(i32);
+    /// let v = vec![1,2,3,4];
+    /// let sum = v.fold_right(Count(0), |a, b| Count(a + b.0));
+    /// assert_eq!(Count(10), sum);
+    /// assert_eq!("4321", v.fold_right(String::new(), |a, b| b + &a.to_string()));
+    /// ```
+    fn fold_right<B, F>(&self, z: B, mut f: F) -> B where F: FnMut(&Self::A, B) -> B {
+      // not every iterator can be reversed, so only the references are collected
+      let refs: Vec<&Self::A> = self.iter().collect();
+      refs.into_iter().rev().fold(z, |b, a| f(a, b))
+    }
+    /// Reduces the values of the Foldable from the left into a single value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use funlib::Foldable::*;
+    /// #[derive(Debug, PartialEq)]
+    /// struct Count(i32);
+    /// let v = vec![1,2,3,4];
+    /// let sum = v.fold_left(Count(0), |b, a| Count(a + b.0));
+    /// assert_eq!(Count(10), sum);
+    /// ```
+    fn fold_left<B, F>(&self, z: B, f: F) -> B where F: FnMut(B, &Self::A) -> B { self.iter().fold(z, f) }
+    /// Using a Monoid and a function to transform the Foldable values form A -> b to reduce the values in the Foldable to a single value of B
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use funlib::Foldable::*;
+    /// use funlib::semigroup::Sum;
+    /// assert_eq!(Sum(10), vec![1,2,3,4].fold_map(|&a| Sum(a)));
+    /// assert_eq!(Sum(0), None.fold_map(|&a: &i32| Sum(a)));
     /// ```
-    fn fold<F>(&'r self, z: A, f: F) -> A where F: FnMut(A, &A) -> A;
+    fn fold_map<B, F>(&self, mut f: F) -> B where F: FnMut(&Self::A) -> B, B: Monoid { self.fold_left(B::mempty(), |b, a| B::mappend(&b, &f(a))) }
     /// Using a Monoid reduce the values in the Foldable to a single value
     /// # Examples
     ///
@@ -162,7 +853,7 @@ pub mod Foldable {
     /// let sum = v.concat();
     /// assert_eq!(10, sum);
     /// ```
-    fn concat(&'r self) -> A where A: Monoid { self.fold(A::mempty(), |a,b| A::mappend(&a, b)) }
+    fn concat(&self) -> Self::A where Self::A: Monoid { self.fold(Self::A::mempty(), |a, b| Self::A::mappend(&a, b)) }
     /// Find a value in the foldable, returns an Option<&_>
     ///
     /// # Examples
@@ -175,7 +866,7 @@ pub mod Foldable {
     /// assert_eq!(Some(&2), s);
     /// assert_eq!(None, n);
     /// ```
-    fn find<F>(&'r self, f: F) -> Option<&A> where F: Fn(&A) -> bool;
+    fn find<F>(&self, mut f: F) -> Option<&Self::A> where F: FnMut(&Self::A) -> bool { self.iter().find(|a| f(a)) }
     /// Check if all values in the foldable returns true for function f
     ///
     /// # Examples
@@ -186,8 +877,8 @@ pub mod Foldable {
     /// assert_eq!(true, v.all(|&a| a < 5));
     /// assert_eq!(false, v.all(|&a| a < 4));
     /// ```
-    fn all<F>(&'r self, f: F) -> bool where F: Fn(&A) -> bool;
-    /// Check if any valu ein the foldable returns true for function f
+    fn all<F>(&self, f: F) -> bool where F: FnMut(&Self::A) -> bool { self.iter().all(f) }
+    /// Check if any value in the foldable returns true for function f
     ///
     /// # Examples
     ///
@@ -197,17 +888,7 @@ pub mod Foldable {
     /// assert_eq!(true, v.any(|&a| a == 4));
     /// assert_eq!(false, v.any(|&a| a == 5));
     /// ```
-    fn any<F>(&'r self, f: F) -> bool where F: Fn(&A) -> bool;
-    /// Filters the foldable for values that meet the predicate
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use funlib::Foldable::*;
-    /// let v = vec![1,2,3,4];
-    /// assert_eq!(vec![&1,&2], v.filter(|&a| a < 3));
-    /// ```
-    fn filter<F>(&'r self, f: F) -> Self::M where F: Fn(&A) -> bool;
+    fn any<F>(&self, f: F) -> bool where F: FnMut(&Self::A) -> bool { self.iter().any(f) }
     /// Checks if the foldable is empty
     ///
     /// # Examples
@@ -219,7 +900,7 @@ pub mod Foldable {
     /// assert_eq!(false, v.is_empty());
     /// assert_eq!(true, v2.is_empty());
     /// ```
-    fn is_empty(&'r self) -> bool;
+    fn is_empty(&self) -> bool { self.iter().next().is_none() }
     /// Checks if the foldable is non empty
     ///
     /// # Examples
@@ -231,56 +912,256 @@ pub mod Foldable {
     /// assert_eq!(true, v.non_empty());
     /// assert_eq!(false, v2.non_empty());
     /// ```
-    fn non_empty(&'r self) -> bool { !self.is_empty() }
+    fn non_empty(&self) -> bool { !self.is_empty() }
+    /// Number of values in the foldable
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use funlib::Foldable::*;
+    /// assert_eq!(3, vec![1,2,3].length());
+    /// assert_eq!(0, None::<i32>.length());
+    /// ```
+    fn length(&self) -> usize { self.iter().count() }
+    /// Number of values that meet the predicate
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use funlib::Foldable::*;
+    /// assert_eq!(2, vec![1,2,3,4].count(|&a| a % 2 == 0));
+    /// ```
+    fn count<F>(&self, mut f: F) -> usize where F: FnMut(&Self::A) -> bool { self.iter().filter(|a| f(a)).count() }
+    /// Adds the values using the `Sum` monoid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use funlib::Foldable::*;
+    /// assert_eq!(10, vec![1,2,3,4].sum());
+    /// assert_eq!(0, None::<i32>.sum());
+    /// ```
+    fn sum(&self) -> Self::A where Self::A: Clone, Sum<Self::A>: Monoid { self.fold_map(|a| Sum(a.clone())).0 }
+    /// Multiplies the values using the `Product` monoid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use funlib::Foldable::*;
+    /// assert_eq!(24, vec![1,2,3,4].product());
+    /// assert_eq!(1, None::<i32>.product());
+    /// ```
+    fn product(&self) -> Self::A where Self::A: Clone, Product<Self::A>: Monoid { self.fold_map(|a| Product(a.clone())).0 }
+    /// The largest value according to the comparator, the last one wins on ties
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use funlib::Foldable::*;
+    /// let v = vec!["bb", "a", "ccc"];
+    /// assert_eq!(Some(&"ccc"), v.maximum_by(|a, b| a.len().cmp(&b.len())));
+    /// assert_eq!(None, Vec::<i32>::new().maximum_by(|a, b| a.cmp(b)));
+    /// ```
+    fn maximum_by<F>(&self, mut f: F) -> Option<&Self::A> where F: FnMut(&Self::A, &Self::A) -> Ordering { self.iter().max_by(|a, b| f(a, b)) }
+    /// The smallest value according to the comparator, the first one wins on ties
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use funlib::Foldable::*;
+    /// let v = vec!["bb", "a", "ccc"];
+    /// assert_eq!(Some(&"a"), v.minimum_by(|a, b| a.len().cmp(&b.len())));
+    /// ```
+    fn minimum_by<F>(&self, mut f: F) -> Option<&Self::A> where F: FnMut(&Self::A, &Self::A) -> Ordering { self.iter().min_by(|a, b| f(a, b)) }
+    /// Checks if the value is in the foldable
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use funlib::Foldable::*;
+    /// assert!(vec![1,2,3].elem(&2));
+    /// assert!(!Some(1).elem(&2));
+    /// ```
+    fn elem(&self, a: &Self::A) -> bool where Self::A: PartialEq { self.any(|b| b == a) }
+    /// Copies the values into a Vec, from left to right
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use funlib::Foldable::*;
+    /// assert_eq!(vec![1], Some(1).to_vec());
+    /// ```
+    fn to_vec(&self) -> Vec<Self::A> where Self::A: Clone { self.iter().cloned().collect() }
+    /// Reduces the values from the left, starting with the first value, returns None when empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use funlib::Foldable::*;
+    /// assert_eq!(Some(-4), vec![1,2,3].fold_left1(|b, a| b - a));
+    /// assert_eq!(None, Vec::<i32>::new().fold_left1(|b, a| b - a));
+    /// ```
+    fn fold_left1<F>(&self, f: F) -> Option<Self::A> where F: FnMut(Self::A, &Self::A) -> Self::A, Self::A: Clone {
+      let mut it = self.iter();
+      it.next().map(|z| it.fold(z.clone(), f))
+    }
+    /// Reduces the values from the right, starting with the last value, returns None when empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use funlib::Foldable::*;
+    /// assert_eq!(Some(2), vec![1,2,3].fold_right1(|a, b| a - b));
+    /// ```
+    fn fold_right1<F>(&self, mut f: F) -> Option<Self::A> where F: FnMut(&Self::A, Self::A) -> Self::A, Self::A: Clone {
+      let refs: Vec<&Self::A> = self.iter().collect();
+      let mut it = refs.into_iter().rev();
+      it.next().map(|z| it.fold(z.clone(), |b, a| f(a, b)))
+    }
+    /// Splits the values in those that meet the predicate and those that don't
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use funlib::Foldable::*;
+    /// assert_eq!((vec![&2, &4], vec![&1, &3]), vec![1,2,3,4].partition(|&a| a % 2 == 0));
+    /// ```
+    fn partition<F>(&self, mut f: F) -> (Vec<&Self::A>, Vec<&Self::A>) where F: FnMut(&Self::A) -> bool { self.iter().partition(|a| f(a)) }
+    /// Reduces the values from the left until f returns `ControlFlow::Break`, the remaining values are not visited
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use funlib::Foldable::*;
+    /// use std::ops::ControlFlow::*;
+    /// let v = vec![1, 2, 3, 4, 5];
+    /// assert_eq!(6, v.fold_while(0, |b, &a| if b + a > 6 { Break(b) } else { Continue(b + a) }));
+    /// assert_eq!(15, v.fold_while(0, |b, &a| Continue(b + a)));
+    /// ```
+    fn fold_while<B, F>(&self, z: B, f: F) -> B where F: FnMut(B, &Self::A) -> ControlFlow<B, B> {
+      match self.iter().try_fold(z, f) {
+        ControlFlow::Continue(b) | ControlFlow::Break(b) => b,
+      }
+    }
+    /// Reduces the values from the left, stopping at the first error
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use funlib::Foldable::*;
+    /// let v = vec![100u8, 100, 100];
+    /// assert_eq!(Err(2), v.try_fold_left(0u8, |b, &a| b.checked_add(a).ok_or(2)));
+    /// assert_eq!(Ok(200), v[1..].to_vec().try_fold_left(0u8, |b, &a| b.checked_add(a).ok_or(2)));
+    /// ```
+    fn try_fold_left<B, E, F>(&self, z: B, f: F) -> Result<B, E> where F: FnMut(B, &Self::A) -> Result<B, E> { self.iter().try_fold(z, f) }
+    /// Reduces the values from the left, threading the Monad returned by f through every step
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use funlib::Foldable::*;
+    /// let divide = |b: &i32, a: &i32| if *a == 0 { None } else { Some(b / a) };
+    /// assert_eq!(Some(5), vec![2, 5].fold_m(50, divide));
+    /// assert_eq!(None, vec![2, 0, 5].fold_m(50, divide));
+    /// assert_eq!(vec![3, -1, 1, -3], vec![1, 2].fold_m(0, |b, a| vec![b + a, b - a]));
+    /// ```
+    fn fold_m<B, M, F>(&self, z: B, f: F) -> M where M: Monad<A = B> + Kind<Target<B> = M>, F: Fn(&B, &Self::A) -> M {
+      self.iter().fold(M::pure_(z), |m, a| m.bind::<B, _>(|b| f(b, a)))
+    }
   }
 
-  /// FoldableS is for Foldables that is not a list of some kind, ex. Option
-  pub trait FoldableS<'r, A: 'r>: HKST<'r, A> {
-    /// Reduces the values of the Foldable into a single value
-    fn fold<F>(&'r self, z: A, f: F) -> A where F: Fn(&A) -> A;
-    /// Find a value in the foldable, returns an Option<&_>
-    fn find<F>(&'r self, f: F) -> Option<&A> where F: Fn(&A) -> bool;
-    /// Check if all values in the foldable returns true for function f
-    fn all<F>(&'r self, f: F) -> bool where F: Fn(&A) -> bool;
-    /// Check if any valu ein the foldable returns true for function f
-    fn any<F>(&'r self, f: F) -> bool where F: Fn(&A) -> bool;
+  /// Filterable is for Foldables that can drop values, ex. Vec or Option
+  pub trait Filterable: Foldable {
     /// Filters the foldable for values that meet the predicate
-    fn filter<F>(&'r self, f: F) -> Self::M where F: Fn(&A) -> bool;
-    /// Checks if the foldable is empty
-    fn is_empty(&'r self) -> bool;
-    /// Checks if the foldable is non empty.
-    fn non_empty(&'r self) -> bool { !self.is_empty() }
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use funlib::Foldable::*;
+    /// let v = vec![1,2,3,4];
+    /// assert_eq!(vec![&1,&2], v.filter(|&a| a < 3));
+    /// assert_eq!(None, Some(7).filter(|&a| a > 10));
+    /// ```
+    fn filter<F>(&self, f: F) -> Self::Target<&Self::A> where F: FnMut(&Self::A) -> bool;
   }
 
-  /// FoladableB is for Hinger Kinded Types where M<A> -> B / M<B>
-  pub trait FoldableB<B>: HKT<B> {
-    /// Reduces the values of the Foldable into a single value
+  /// FoldableIterator folds any Iterator lazily, without collecting it first
+  pub trait FoldableIterator: Iterator + Sized {
+    /// Transform every value with f and combine the results with their Monoid
     ///
     /// # Examples
     ///
     /// ```
     /// use funlib::Foldable::*;
-    /// #[derive(Debug, PartialEq)]
-    /// struct Count(i32);
-    /// let v = vec![1,2,3,4];
-    /// let sum = v.fold_right(Count(0), |a, b| Count(a + b.0));
-    /// assert_eq!(Count(10), sum);
+    /// use funlib::semigroup::{Max, Sum};
+    /// assert_eq!(Sum(5050), (1..=100).fold_map(Sum));
+    /// assert_eq!(Max(4), [3, 1, 4].iter().fold_map(|&a| Max(a)));
     /// ```
-    fn fold_right<F>(&self, z: B, f: F) -> B where F: Fn(&Self::A, B) -> B;
-    /// Reduces the values of the Foldable into a single value
+    fn fold_map<B, F>(self, f: F) -> B where F: FnMut(Self::Item) -> B, B: Monoid;
+    /// Combine every value with its Monoid
     ///
     /// # Examples
     ///
     /// ```
     /// use funlib::Foldable::*;
-    /// #[derive(Debug, PartialEq)]
-    /// struct Count(i32);
-    /// let v = vec![1,2,3,4];
-    /// let sum: Count = v.fold_left(Count(0), |b, a| Count(a + b.0));
-    /// assert_eq!(Count(10), sum);
+    /// let words = ["a", "b", "c"];
+    /// assert_eq!("abc", words.iter().map(|s| s.to_string()).concat());
     /// ```
-    fn fold_left<F>(&self, z: B, f: F) -> B where F: Fn(B, &Self::A) -> B;
-    /// Using a Monoid and a function to transform the Foldable values form A -> b to reduce the values in the Foldable to a single value of B
-    fn fold_map<F>(&self, f: F) -> B where F: Fn(&Self::A) -> B, B: Monoid { self.fold_left(B::mempty(), |b, a| B::mappend(&b, &f(&a))) }
+    fn concat(self) -> Self::Item where Self::Item: Monoid;
+  }
+
+  /// Foldable1 is for Foldables that always hold at least one value, so they can be reduced with only a Semigroup
+  pub trait Foldable1: Foldable {
+    /// Reduces the values from left to right, starting by mapping the first value with g
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use funlib::Foldable::*;
+    /// use funlib::nonempty::NonEmpty;
+    /// let n = NonEmpty { head: 1, tail: vec![2, 3] };
+    /// assert_eq!("1-2-3", n.reduce_left_map(|a| a.to_string(), |b, a| format!("{}-{}", b, a)));
+    /// ```
+    fn reduce_left_map<'a, B, G, F>(&'a self, g: G, f: F) -> B where G: FnOnce(&'a Self::A) -> B, F: FnMut(B, &'a Self::A) -> B;
+    /// Using a Semigroup reduce the values to a single value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use funlib::Foldable::*;
+    /// use funlib::nonempty::NonEmpty;
+    /// assert_eq!(6, NonEmpty { head: 1, tail: vec![2, 3] }.reduce());
+    /// ```
+    fn reduce(&self) -> Self::A where Self::A: Semigroup { self.reduce_map(|a| a.clone()) }
+    /// Transform the values with f and reduce the results with their Semigroup
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use funlib::Foldable::*;
+    /// use funlib::nonempty::NonEmpty;
+    /// use funlib::semigroup::Max;
+    /// assert_eq!(Max(3), NonEmpty { head: 1, tail: vec![3, 2] }.reduce_map(|&a| Max(a)));
+    /// ```
+    fn reduce_map<B, F>(&self, f: F) -> B where F: Fn(&Self::A) -> B, B: Semigroup {
+      self.reduce_left_map(&f, |b, a| b.mappend(&f(a)))
+    }
+    /// The first value
+    fn head(&self) -> &Self::A { self.reduce_left_map(|a| a, |b, _| b) }
+    /// The last value
+    fn last(&self) -> &Self::A { self.reduce_left_map(|a| a, |_, a| a) }
+    /// The largest value, the last one wins on ties
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use funlib::Foldable::*;
+    /// use funlib::nonempty::NonEmpty;
+    /// let n = NonEmpty { head: 2, tail: vec![3, 1] };
+    /// assert_eq!((&3, &1), (n.max(), n.min()));
+    /// ```
+    fn max(&self) -> &Self::A where Self::A: Ord { self.reduce_left_map(|a| a, |b, a| if a >= b { a } else { b }) }
+    /// The smallest value, the first one wins on ties
+    fn min(&self) -> &Self::A where Self::A: Ord { self.reduce_left_map(|a| a, |b, a| if a < b { a } else { b }) }
   }
 }
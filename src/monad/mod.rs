@@ -2,12 +2,12 @@
 //! Monad implementations and tests
 //!
 
-use crate::Monad;
+use crate::{Kind, Monad};
 use std::boxed::Box;
 use std::rc::Rc;
 
-impl<A, B> Monad<B> for Option<A> {
-  fn bind<F>(&self, mut f: F) -> Option<B> where F: FnMut(&A) -> Option<B> {
+impl<A> Monad for Option<A> {
+  fn bind<B, F>(&self, mut f: F) -> Option<B> where F: FnMut(&A) -> Option<B> {
     match *self {
       Some(ref a) => f(a),
       None => None,
@@ -15,24 +15,85 @@ impl<A, B> Monad<B> for Option<A> {
   }
 }
 
-impl<A, B> Monad<B> for Box<A> {
-  fn bind<F>(&self, mut f: F) -> Box<B> where F: FnMut(&A) -> Box<B> {
+impl<A> Monad for Box<A> {
+  fn bind<B, F>(&self, mut f: F) -> Box<B> where F: FnMut(&A) -> Box<B> {
     f(self)
   }
 }
 
-impl<A, B> Monad<B> for Rc<A> {
-  fn bind<F>(&self, mut f: F) -> Rc<B> where F: FnMut(&A) -> Rc<B> {
+impl<A> Monad for Rc<A> {
+  fn bind<B, F>(&self, mut f: F) -> Rc<B> where F: FnMut(&A) -> Rc<B> {
     f(self)
   }
 }
 
-impl<A, B> Monad<B> for Vec<A> {
-  fn bind<F>(&self, f: F) -> Vec<B> where F: FnMut(&A) -> Vec<B> {
+impl<A> Monad for Vec<A> {
+  fn bind<B, F>(&self, f: F) -> Vec<B> where F: FnMut(&A) -> Vec<B> {
     self.iter().flat_map(f).collect()
   }
 }
 
+impl<A, E: Clone> Monad for Result<A, E> {
+  fn bind<B, F>(&self, mut f: F) -> Result<B, E> where F: FnMut(&A) -> Result<B, E> {
+    match *self {
+      Ok(ref a) => f(a),
+      Err(ref e) => Err(e.clone()),
+    }
+  }
+}
+
+/// Keeps the values for which the monadic predicate holds, threading the Monad through every step
+///
+/// # Examples
+///
+/// ```
+/// use funlib::monad::filter_m;
+/// let small = |a: &i32| if *a < 10 { Some(*a % 2 == 0) } else { None };
+/// assert_eq!(Some(vec![2, 4]), filter_m(vec![1, 2, 3, 4], small));
+/// assert_eq!(None, filter_m(vec![1, 20], small));
+/// assert_eq!(vec![vec![1, 2], vec![1], vec![2], vec![]], filter_m(vec![1, 2], |_| vec![true, false]));
+/// ```
+pub fn filter_m<A, I, M, F>(xs: I, f: F) -> M::Target<Vec<A>>
+  where I: IntoIterator<Item = A>, A: Clone, M: Monad<A = bool>, F: Fn(&A) -> M,
+        M::Target<Vec<A>>: Monad<A = Vec<A>> + Kind<Target<Vec<A>> = M::Target<Vec<A>>> {
+  concat_m(xs.into_iter().map(|x| f(&x).fmap(|&keep| if keep { vec![x.clone()] } else { vec![] })).collect())
+}
+
+/// Combines two sequences pairwise with a monadic function and collects the results
+///
+/// Stops at the end of the shorter sequence.
+///
+/// # Examples
+///
+/// ```
+/// use funlib::monad::zip_with_m;
+/// let div = |a: &i32, b: &i32| if *b == 0 { None } else { Some(a / b) };
+/// assert_eq!(Some(vec![5, 2]), zip_with_m(vec![10, 8], vec![2, 4, 6], div));
+/// assert_eq!(None, zip_with_m(vec![10, 8], vec![2, 0], div));
+/// ```
+pub fn zip_with_m<A, B, C, I, J, M, F>(xs: I, ys: J, f: F) -> M::Target<Vec<C>>
+  where I: IntoIterator<Item = A>, J: IntoIterator<Item = B>, C: Clone, M: Monad<A = C>, F: Fn(&A, &B) -> M,
+        M::Target<Vec<C>>: Monad<A = Vec<C>> + Kind<Target<Vec<C>> = M::Target<Vec<C>>> {
+  concat_m(xs.into_iter().zip(ys).map(|(x, y)| f(&x, &y).fmap(|c| vec![c.clone()])).collect())
+}
+
+/// Runs the Monads from left to right and appends their results
+///
+/// The halves are combined recursively, so every value is cloned once per level
+/// rather than once per element.
+pub(crate) fn concat_m<A, M>(mut ms: Vec<M>) -> M
+  where A: Clone, M: Monad<A = Vec<A>> + Kind<Target<Vec<A>> = M> {
+  match ms.len() {
+    0 => M::pure_(vec![]),
+    1 => ms.pop().unwrap(),
+    n => {
+      let r = ms.split_off(n / 2);
+      let (l, r) = (concat_m(ms), concat_m(r));
+      l.bind::<Vec<A>, _>(|lv| r.fmap(|rv| lv.iter().chain(rv).cloned().collect::<Vec<A>>()))
+    }
+  }
+}
+
 #[cfg(test)]
 mod test {
   use crate::Monad;
@@ -41,6 +102,7 @@ mod test {
   use std::rc::Rc;
 
   #[test]
+  #[allow(clippy::identity_op)]
   fn option() {
     assert_eq!(Some("hello"), Some(5).fmap(|_i| "hello"));
     assert_eq!(Option::<i32>::pure_(10), Some(5).bind(|i| Some(i * 1)).fmap(|i| i * 2));
@@ -58,10 +120,161 @@ mod test {
   }
 
   #[test]
+  #[allow(clippy::identity_op)]
   fn vec() {
     let mut vec = Vec::pure_(1);
     vec.push(2);
     vec.push(3);
     assert_eq!(vec![1,2,2,4,3,6], vec.bind(|x| vec![x * 1, x * 2]));
   }
+
+  #[test]
+  fn mdo_option() {
+    let r = mdo! {
+      x <- Some(1);
+      let y = x + 1;
+      guard y > 1;
+      z <- Some(y * 2);
+      pure x + z
+    };
+    assert_eq!(Some(5), r);
+    let r: Option<i32> = mdo! {
+      x <- Some(1);
+      guard x > 1;
+      pure x
+    };
+    assert_eq!(None, r);
+    let r: Option<i32> = mdo! {
+      x <- Some(1);
+      _ <- None::<i32>;
+      pure x
+    };
+    assert_eq!(None, r);
+  }
+
+  #[test]
+  fn mdo_vec() {
+    let r = mdo! {
+      x <- vec![1, 2, 3];
+      y <- vec![x, x * 10];
+      guard y % 2 == 0;
+      pure (x, y)
+    };
+    assert_eq!(vec![(1, 10), (2, 2), (2, 20), (3, 30)], r);
+  }
+
+  #[test]
+  fn mdo_box_rc() {
+    let b = mdo! {
+      x <- Box::new(2);
+      let y: i32 = x * 3;
+      (a, c) <- Box::new((x, y));
+      Box::new(a + c)
+    };
+    assert_eq!(Box::new(8), b);
+    let r = mdo! {
+      x <- Rc::new("a".to_string());
+      y <- Rc::new(format!("{}b", x));
+      pure y
+    };
+    assert_eq!(Rc::new("ab".to_string()), r);
+  }
+
+  #[test]
+  fn mdo_result() {
+    fn parse(s: &str) -> Result<i32, String> { s.parse::<i32>().map_err(|e| e.to_string()) }
+    let r: Result<i32, String> = mdo! {
+      a <- parse("1");
+      b <- parse("2");
+      Ok::<(), String>(());
+      pure a + b
+    };
+    assert_eq!(Ok(3), r);
+    let r: Result<i32, String> = mdo! {
+      a <- parse("1");
+      b <- parse("x");
+      pure a + b
+    };
+    assert_eq!(Err("invalid digit found in string".to_string()), r);
+  }
+
+  #[test]
+  fn mdo_borrows_outer_values() {
+    let prefix = String::from("p");
+    let r = mdo! {
+      x <- Some(1);
+      y <- Some(2);
+      pure format!("{}{}", prefix, x + y)
+    };
+    assert_eq!(Some("p3".to_string()), r);
+    assert_eq!("p", prefix);
+  }
+
+  #[test]
+  fn generic() {
+    fn pair_up<M: Monad<A = i32>>(m: &M) -> M::Target<(i32, i32)>
+    where M::Target<(i32, i32)>: Applicative<A = (i32, i32)> {
+      m.bind(|&a| M::Target::<(i32, i32)>::pure_((a, a * 2)))
+    }
+    assert_eq!(Some((2, 4)), pair_up(&Some(2)));
+    assert_eq!(vec![(1, 2), (2, 4)], pair_up(&vec![1, 2]));
+    assert_eq!(Rc::new((3, 6)), pair_up(&Rc::new(3)));
+  }
+
+  #[test]
+  fn join() {
+    assert_eq!(Some(1), Some(Some(1)).join());
+    assert_eq!(None::<i32>, Some(None).join());
+    assert_eq!(vec![1, 2, 3], vec![vec![1], vec![], vec![2, 3]].join());
+    assert_eq!(Box::new(1), Box::new(Box::new(1)).join());
+    assert_eq!(Rc::new(1), Rc::new(Rc::new(1)).join());
+  }
+
+  #[test]
+  fn then() {
+    assert_eq!(Some(2), Some(1).then(Some(2)));
+    assert_eq!(None, Some(1).then(None::<i32>));
+    assert_eq!(vec![3, 4, 3, 4], vec![1, 2].then(vec![3, 4]));
+    assert_eq!(Box::new("b"), Box::new(1).then(Box::new("b")));
+    assert_eq!(Rc::new("b"), Rc::new(1).then(Rc::new("b")));
+  }
+
+  #[test]
+  fn replicate_m() {
+    assert_eq!(Some(vec![]), Some(1).replicate_m(0));
+    assert_eq!(None, None::<i32>.replicate_m(2));
+    assert_eq!(8, vec![1, 2].replicate_m(3).len());
+    assert_eq!(Box::new(vec![7, 7]), Box::new(7).replicate_m(2));
+    assert_eq!(Rc::new(vec![7]), Rc::new(7).replicate_m(1));
+  }
+
+  #[test]
+  fn when_unless_void() {
+    assert_eq!(vec![(), ()], Vec::when(true, vec![(), ()]));
+    assert_eq!(vec![()], Vec::when(false, vec![]));
+    assert_eq!(vec![()], Vec::unless(true, vec![]));
+    assert_eq!(Box::new(()), Box::unless(false, Box::new(())));
+    assert_eq!(Rc::new(()), Rc::new("a").void());
+    assert_eq!(None, None::<i32>.void());
+  }
+
+  #[test]
+  fn filter_and_zip_m() {
+    use super::{filter_m, zip_with_m};
+    assert_eq!(Box::new(vec![2]), filter_m(vec![1, 2, 3], |a| Box::new(*a == 2)));
+    assert_eq!(Rc::new(vec![1, 3]), filter_m(vec![1, 2, 3], |a| Rc::new(a % 2 == 1)));
+    assert_eq!(Some(Vec::<i32>::new()), filter_m(vec![], |_: &i32| Some(true)));
+    assert_eq!(Some((1..=50).map(|a| a * 2).collect()), filter_m(1..=100, |a| Some(a % 2 == 0)));
+    assert_eq!(Err(3), filter_m(1..=100, |a| if a % 3 == 0 { Err(*a) } else { Ok(true) }));
+    assert_eq!(vec![vec![11, 22], vec![11, -18], vec![-9, 22], vec![-9, -18]], zip_with_m(vec![1, 2], vec![10, 20], |a, b| vec![a + b, a - b]));
+    assert_eq!(Box::new(vec!["1a".to_string()]), zip_with_m(vec![1], vec!['a'], |a, b| Box::new(format!("{}{}", a, b))));
+  }
+
+  #[test]
+  fn result() {
+    fn over5(i: &i32) -> Result<i32, String> { if *i > 5 { Ok(*i) } else { Err(format!("{} is too small", i)) }}
+    assert_eq!(Result::<i32, String>::pure_(12), Ok(6).bind(over5).bind(|i| Ok(i * 2)));
+    assert_eq!(Err("4 is too small".to_string()), Ok(4).bind(over5).bind(|i| Ok(i * 2)));
+    assert_eq!(Err("failed".to_string()), Err::<i32, String>("failed".to_string()).bind(over5));
+  }
 }
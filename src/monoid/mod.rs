@@ -2,7 +2,12 @@
 //! Monoid implementations and tests
 //!
 
-use crate::Monoid;
+use crate::{Monoid, Semigroup};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, LinkedList, VecDeque};
+use std::hash::Hash;
+use crate::semigroup::{All, Any, Checked, Dual, Endo, First, FirstOk, KahanSum, Last, Max, Min, Product, Sum};
+use std::num::{Saturating, Wrapping};
 
 macro_rules! monoid {
   ($t:ident, $v:expr) => {
@@ -16,13 +21,157 @@ monoid!(i8, 0);
 monoid!(i16, 0);
 monoid!(i32, 0);
 monoid!(i64, 0);
+monoid!(i128, 0);
+monoid!(isize, 0);
 monoid!(u8, 0);
 monoid!(u16, 0);
 monoid!(u32, 0);
 monoid!(u64, 0);
+monoid!(u128, 0);
+monoid!(usize, 0);
 monoid!(f32, 0.0);
 monoid!(f64, 0.0);
 
+impl Monoid for () {
+  fn mempty() -> Self {}
+}
+
+macro_rules! monoid_tuple {
+  ($($t:ident),+) => {
+    impl<$($t: Monoid),+> Monoid for ($($t,)+) {
+      fn mempty() -> Self { ($($t::mempty(),)+) }
+    }
+  }
+}
+
+monoid_tuple!(A);
+monoid_tuple!(A, B);
+monoid_tuple!(A, B, C);
+monoid_tuple!(A, B, C, D);
+monoid_tuple!(A, B, C, D, E);
+monoid_tuple!(A, B, C, D, E, F);
+monoid_tuple!(A, B, C, D, E, F, G);
+monoid_tuple!(A, B, C, D, E, F, G, H);
+monoid_tuple!(A, B, C, D, E, F, G, H, I);
+monoid_tuple!(A, B, C, D, E, F, G, H, I, J);
+monoid_tuple!(A, B, C, D, E, F, G, H, I, J, K);
+monoid_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+macro_rules! numeric_wrappers {
+  ($t:ident, $zero:expr, $one:expr, $min:expr, $max:expr) => {
+    impl Monoid for Sum<$t> {
+      fn mempty() -> Self { Sum($zero) }
+    }
+    impl Monoid for Product<$t> {
+      fn mempty() -> Self { Product($one) }
+    }
+    impl Monoid for Min<$t> {
+      fn mempty() -> Self { Min($max) }
+    }
+    impl Monoid for Max<$t> {
+      fn mempty() -> Self { Max($min) }
+    }
+  }
+}
+
+numeric_wrappers!(i8, 0, 1, i8::MIN, i8::MAX);
+numeric_wrappers!(i16, 0, 1, i16::MIN, i16::MAX);
+numeric_wrappers!(i32, 0, 1, i32::MIN, i32::MAX);
+numeric_wrappers!(i64, 0, 1, i64::MIN, i64::MAX);
+numeric_wrappers!(i128, 0, 1, i128::MIN, i128::MAX);
+numeric_wrappers!(isize, 0, 1, isize::MIN, isize::MAX);
+numeric_wrappers!(u8, 0, 1, u8::MIN, u8::MAX);
+numeric_wrappers!(u16, 0, 1, u16::MIN, u16::MAX);
+numeric_wrappers!(u32, 0, 1, u32::MIN, u32::MAX);
+numeric_wrappers!(u64, 0, 1, u64::MIN, u64::MAX);
+numeric_wrappers!(u128, 0, 1, u128::MIN, u128::MAX);
+numeric_wrappers!(usize, 0, 1, usize::MIN, usize::MAX);
+macro_rules! saturating {
+  ($t:ident) => {
+    impl Monoid for Saturating<$t> {
+      fn mempty() -> Self { Saturating(0) }
+    }
+  }
+}
+
+saturating!(u8);
+saturating!(u16);
+saturating!(u32);
+saturating!(u64);
+saturating!(u128);
+saturating!(usize);
+
+macro_rules! integer_wrappers {
+  ($t:ident) => {
+    impl Monoid for Wrapping<$t> {
+      fn mempty() -> Self { Wrapping(0) }
+    }
+    impl Monoid for Checked<$t> {
+      fn mempty() -> Self { Checked(Some(0)) }
+    }
+  }
+}
+
+integer_wrappers!(i8);
+integer_wrappers!(i16);
+integer_wrappers!(i32);
+integer_wrappers!(i64);
+integer_wrappers!(i128);
+integer_wrappers!(isize);
+integer_wrappers!(u8);
+integer_wrappers!(u16);
+integer_wrappers!(u32);
+integer_wrappers!(u64);
+integer_wrappers!(u128);
+integer_wrappers!(usize);
+
+numeric_wrappers!(f32, 0.0, 1.0, f32::NEG_INFINITY, f32::INFINITY);
+numeric_wrappers!(f64, 0.0, 1.0, f64::NEG_INFINITY, f64::INFINITY);
+
+impl<A: Clone> Monoid for First<A> {
+  fn mempty() -> Self {
+    First(None)
+  }
+}
+
+impl<A: Clone> Monoid for Last<A> {
+  fn mempty() -> Self {
+    Last(None)
+  }
+}
+
+impl Monoid for Any {
+  fn mempty() -> Self {
+    Any(false)
+  }
+}
+
+impl Monoid for All {
+  fn mempty() -> Self {
+    All(true)
+  }
+}
+
+impl Monoid for KahanSum<f32> {
+  fn mempty() -> Self { KahanSum::new(0.0) }
+}
+
+impl Monoid for KahanSum<f64> {
+  fn mempty() -> Self { KahanSum::new(0.0) }
+}
+
+impl<A: Monoid> Monoid for Dual<A> {
+  fn mempty() -> Self {
+    Dual(A::mempty())
+  }
+}
+
+impl<'a, A: 'a> Monoid for Endo<'a, A> {
+  fn mempty() -> Self {
+    Endo::new(|a| a)
+  }
+}
+
 impl<A: Monoid> Monoid for Option<A> {
   fn mempty() -> Self {
     None::<A>
@@ -35,20 +184,89 @@ impl<A: Monoid> Monoid for Box<A> {
   }
 }
 
-impl<'a> Monoid for &'a str {
+impl<A: Clone> Monoid for Vec<A> {
+  fn mempty() -> Self {
+    vec![]
+  }
+}
+
+impl<A: Clone> Monoid for VecDeque<A> {
+  fn mempty() -> Self {
+    VecDeque::new()
+  }
+}
+
+impl<A: Clone> Monoid for LinkedList<A> {
+  fn mempty() -> Self {
+    LinkedList::new()
+  }
+}
+
+impl<A: Eq + Hash + Clone> Monoid for HashSet<A> {
+  fn mempty() -> Self {
+    HashSet::new()
+  }
+}
+
+impl<A: Ord + Clone> Monoid for BTreeSet<A> {
+  fn mempty() -> Self {
+    BTreeSet::new()
+  }
+}
+
+impl<K: Eq + Hash + Clone, V: Semigroup> Monoid for HashMap<K, V> {
+  fn mempty() -> Self {
+    HashMap::new()
+  }
+}
+
+impl<K: Ord + Clone, V: Semigroup> Monoid for BTreeMap<K, V> {
+  fn mempty() -> Self {
+    BTreeMap::new()
+  }
+}
+
+impl Monoid for String {
   fn mempty() -> Self {
-    ""
+    String::new()
+  }
+}
+
+impl Monoid for Cow<'_, str> {
+  fn mempty() -> Self {
+    Cow::Borrowed("")
+  }
+}
+
+impl<A: Monoid, E: Monoid> Monoid for Result<A, E> {
+  fn mempty() -> Self {
+    Ok(A::mempty())
+  }
+}
+
+impl<A: Clone, E: Monoid> Monoid for FirstOk<A, E> {
+  fn mempty() -> Self {
+    FirstOk(Err(E::mempty()))
   }
 }
 
 #[cfg(test)]
 mod test {
   use crate::Monoid;
+  use crate::Foldable::*;
+  use crate::semigroup::{All, Any, Checked, Dual, Endo, First, Last, Max, Min, Product, Sum};
+  use std::num::{Saturating, Wrapping};
+  use std::borrow::Cow;
+  use std::collections::HashMap;
 
   #[cfg(test)]
   mod laws {
     use crate::Monoid;
     use crate::Semigroup;
+    use crate::semigroup::{All, Any, Checked, Dual, First, FirstOk, KahanSum, Last, Max, Min, Product, Sum};
+    use std::num::{Saturating, Wrapping};
+    use std::borrow::Cow;
+    use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, LinkedList, VecDeque};
 
     macro_rules! laws {
       ($t: ident, $v: expr, $v2: expr, $v3: expr) => {
@@ -72,10 +290,62 @@ mod test {
         }
       };
     }
+    macro_rules! laws3 {
+      ($n: ident, $t: ident, $t2: ident, $t3: ident, $v: expr, $v2: expr, $v3: expr) => {
+        #[allow(non_snake_case)]
+        #[test]
+        fn $n() {
+          assert_eq!($v, $t::<$t2, $t3>::mempty().mappend(&$v));
+          assert_eq!($v, $v.mappend(&$t::mempty()));
+          assert_eq!($v.mappend(&$v2.mappend(&$v3)),($v.mappend(&$v2)).mappend(&$v3));
+        }
+      };
+    }
+    macro_rules! laws_ty {
+      ($n: ident, $t: ty, $v: expr, $v2: expr, $v3: expr) => {
+        #[test]
+        fn $n() {
+          assert_eq!($v, <$t>::mempty().mappend(&$v));
+          assert_eq!($v, $v.mappend(&<$t>::mempty()));
+          assert_eq!($v.mappend(&$v2.mappend(&$v3)),($v.mappend(&$v2)).mappend(&$v3));
+        }
+      };
+    }
     laws!(i32, 5i32, 6i32, 10i32);
     laws!(u64, 5u64, 6u64, 10u64);
     laws2!(Option, u8, Some(5u8), Some(6u8), Some(10u8));
     laws2!(Box, i64, Box::new(5i64), Box::new(6i64), Box::new(10i64));
+    laws2!(Vec, u8, vec![5u8], vec![6u8, 7u8], vec![10u8]);
+    laws_ty!(i128_, i128, i128::MAX, 5i128, -3i128);
+    laws_ty!(usize_, usize, usize::MAX, 5usize, 3usize);
+    laws_ty!(wrapping, Wrapping<u8>, Wrapping(200u8), Wrapping(100u8), Wrapping(7u8));
+    laws_ty!(saturating, Saturating<u8>, Saturating(200u8), Saturating(100u8), Saturating(50u8));
+    laws_ty!(checked, Checked<u8>, Checked::new(200u8), Checked::new(50u8), Checked::new(6u8));
+    laws_ty!(unit, (), (), (), ());
+    laws_ty!(pair, (i32, String), (1, "a".to_string()), (2, "b".to_string()), (3, "c".to_string()));
+    laws_ty!(triple, (Sum<i32>, Max<u8>, Vec<i32>), (Sum(1), Max(2u8), vec![1]), (Sum(2), Max(9u8), vec![]), (Sum(3), Max(1u8), vec![3]));
+    laws_ty!(vec_deque, VecDeque<u8>, VecDeque::from(vec![5u8]), VecDeque::from(vec![6u8]), VecDeque::from(vec![10u8]));
+    laws_ty!(linked_list, LinkedList<u8>, LinkedList::from([5u8]), LinkedList::from([6u8]), LinkedList::from([10u8]));
+    laws_ty!(hash_set, HashSet<u8>, HashSet::from([5u8]), HashSet::from([5u8, 6u8]), HashSet::from([10u8]));
+    laws_ty!(btree_set, BTreeSet<u8>, BTreeSet::from([5u8]), BTreeSet::from([5u8, 6u8]), BTreeSet::from([10u8]));
+    laws_ty!(hash_map, HashMap<u8, i32>, HashMap::from([(1u8, 5i32)]), HashMap::from([(1u8, 6i32), (2u8, 1i32)]), HashMap::from([(2u8, 10i32)]));
+    laws_ty!(btree_map, BTreeMap<u8, String>, BTreeMap::from([(1u8, "a".to_string())]), BTreeMap::from([(1u8, "b".to_string())]), BTreeMap::from([(1u8, "c".to_string())]));
+    laws_ty!(string, String, "a".to_string(), "b".to_string(), "c".to_string());
+    laws_ty!(cow_str, Cow<str>, Cow::Borrowed("a"), Cow::<str>::Owned("b".to_string()), Cow::Borrowed(""));
+    laws_ty!(sum, Sum<u8>, Sum(5u8), Sum(6u8), Sum(10u8));
+    laws_ty!(product, Product<i64>, Product(5i64), Product(6i64), Product(10i64));
+    laws_ty!(min, Min<i32>, Min(5i32), Min(-6i32), Min(10i32));
+    laws_ty!(max, Max<f64>, Max(5.0f64), Max(6.0f64), Max(-10.0f64));
+    laws_ty!(first, First<i32>, First(Some(5i32)), First(None), First(Some(10i32)));
+    laws_ty!(last, Last<i32>, Last(Some(5i32)), Last(None), Last(Some(10i32)));
+    laws_ty!(kahan, KahanSum<f64>, KahanSum::new(0.5), KahanSum::new(1.25), KahanSum::new(-3.0));
+    laws_ty!(kahan_rounding, KahanSum<f64>, KahanSum::new(0.1), KahanSum::new(0.2), KahanSum::new(0.3));
+    laws_ty!(dual, Dual<Last<i32>>, Dual(Last(Some(5i32))), Dual(Last(None)), Dual(Last(Some(10i32))));
+    laws_ty!(any, Any, Any(false), Any(true), Any(false));
+    laws_ty!(all, All, All(true), All(false), All(true));
+    laws3!(Result, Result, i32, u8, Ok::<i32, u8>(5), Ok::<i32, u8>(6), Ok::<i32, u8>(10));
+    laws3!(ResultErr, Result, i32, u8, Ok::<i32, u8>(5), Err::<i32, u8>(6), Err::<i32, u8>(10));
+    laws3!(first_ok, FirstOk, i32, u8, FirstOk(Err::<i32, u8>(5)), FirstOk(Ok::<i32, u8>(6)), FirstOk(Err::<i32, u8>(10)));
   }
 
   #[test]
@@ -93,14 +363,88 @@ mod test {
     assert_eq!(None::<i64>, Option::<i64>::mempty());
   }
 
+  #[test]
+  fn wrappers() {
+    assert_eq!(Sum(0), Sum::<i32>::mempty());
+    assert_eq!(Product(1), Product::<u16>::mempty());
+    assert_eq!(Min(u8::MAX), Min::<u8>::mempty());
+    assert_eq!(Max(f32::NEG_INFINITY), Max::<f32>::mempty());
+    assert_eq!(First(None), First::<i32>::mempty());
+    assert_eq!(Any(false), Any::mempty());
+    assert_eq!(All(true), All::mempty());
+    assert_eq!(5, Endo::<i32>::mempty().run(5));
+  }
+
+  #[test]
+  fn fold_map_wrappers() {
+    let v = vec![3, 1, 4, 1, 5];
+    assert_eq!(Sum(14), v.fold_map(|&a| Sum(a)));
+    assert_eq!(Product(60), v.fold_map(|&a| Product(a)));
+    assert_eq!(Min(1), v.fold_map(|&a| Min(a)));
+    assert_eq!(Max(5), v.fold_map(|&a| Max(a)));
+    assert_eq!(First(Some(3)), v.fold_map(|&a| First(Some(a))));
+    assert_eq!(Last(Some(5)), v.fold_map(|&a| Last(Some(a))));
+    assert_eq!(All(true), v.fold_map(|&a| All(a > 0)));
+    assert_eq!(Any(false), v.fold_map(|&a| Any(a > 5)));
+    assert_eq!(Dual(First(Some(5))), v.fold_map(|&a| Dual(First(Some(a)))));
+    assert_eq!(51413, v.fold_map(|&a| Endo::new(move |i: i32| i * 10 + a)).run(0));
+    assert_eq!(Product(60), v.iter().map(|&a| Product(a)).collect::<Vec<_>>().concat());
+  }
+
+  #[test]
+  fn result_i32() {
+    assert_eq!(Ok::<i32, u8>(0), Result::<i32, u8>::mempty());
+  }
+
   #[test]
   fn _box_i8() {
     assert_eq!(Box::new(0), Box::<i8>::mempty());
   }
 
   #[test]
-  fn _box_str() {
-    assert_eq!(Box::new(""), Box::<&str>::mempty());
+  fn _box_string() {
+    assert_eq!(Box::new(String::new()), Box::<String>::mempty());
+  }
+
+  #[test]
+  fn overflow_concat() {
+    let v = vec![200u8, 100, 50];
+    assert_eq!(94u8, Foldable::concat(&v));
+    assert_eq!(Wrapping(94u8), v.fold_map(|&a| Wrapping(a)));
+    assert_eq!(Sum(94u8), v.fold_map(|&a| Sum(a)));
+    assert_eq!(Product(64u8), v.fold_map(|&a| Product(a)));
+    assert_eq!(Saturating(255u8), v.fold_map(|&a| Saturating(a)));
+    assert_eq!(Checked(None), v.fold_map(|&a| Checked::new(a)));
+    assert_eq!(Checked(Some(6usize)), vec![1usize, 2, 3].fold_map(|&a| Checked::new(a)));
+  }
+
+  #[test]
+  fn tuple_fold_map() {
+    let v = vec![3, 1, 4, 1, 5];
+    assert_eq!((Sum(14), Max(5), Sum(5)), v.fold_map(|&a| (Sum(a), Max(a), Sum(1))));
+    assert_eq!((), v.fold_map(|_| ()));
+  }
+
+  #[test]
+  fn map_concat() {
+    let counts = vec![
+      HashMap::from([("a", Sum(1)), ("b", Sum(2))]),
+      HashMap::from([("a", Sum(3))]),
+      HashMap::from([("c", Sum(4))]),
+    ];
+    assert_eq!(HashMap::from([("a", Sum(4)), ("b", Sum(2)), ("c", Sum(4))]), counts.concat());
+  }
+
+  #[test]
+  fn string_concat() {
+    let v = vec!["hello".to_string(), ", ".to_string(), "world".to_string()];
+    assert_eq!("hello, world", Foldable::concat(&v));
+  }
+
+  #[test]
+  fn cow_str_concat() {
+    let v: Vec<Cow<str>> = vec![Cow::Borrowed("hello"), Cow::Owned(", ".to_string()), Cow::Borrowed("world")];
+    assert_eq!("hello, world", Foldable::concat(&v));
   }
 
 }
@@ -0,0 +1,257 @@
+//!
+//! NonEmpty collection implementations and tests
+//!
+
+use crate::{Applicative, Comonad, Functor, Kind, Monad, Semigroup};
+use crate::Foldable::{Foldable, Foldable1};
+
+hkt!(NonEmpty);
+
+/// A list that always holds at least one value
+///
+/// # Examples
+///
+/// ```
+/// use funlib::nonempty::NonEmpty;
+/// let mut n = NonEmpty::new(1);
+/// n.push(2);
+/// assert_eq!(1, n.head);
+/// assert_eq!(vec![1, 2], n.into_vec());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NonEmpty<A> {
+  /// The first value
+  pub head: A,
+  /// The remaining values
+  pub tail: Vec<A>,
+}
+
+impl<A> NonEmpty<A> {
+  /// Create a NonEmpty holding a single value
+  pub fn new(head: A) -> Self {
+    NonEmpty { head, tail: vec![] }
+  }
+
+  /// Create a NonEmpty from a Vec, returns None when the Vec is empty
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use funlib::nonempty::NonEmpty;
+  /// assert_eq!(None, NonEmpty::<i32>::from_vec(vec![]));
+  /// assert_eq!(Some(NonEmpty { head: 1, tail: vec![2] }), NonEmpty::from_vec(vec![1, 2]));
+  /// ```
+  pub fn from_vec(v: Vec<A>) -> Option<Self> {
+    NonEmpty::try_from(v).ok()
+  }
+
+  /// Convert the NonEmpty into a Vec
+  pub fn into_vec(self) -> Vec<A> {
+    let mut v = Vec::with_capacity(self.len());
+    v.push(self.head);
+    v.extend(self.tail);
+    v
+  }
+
+  /// Add a value to the end
+  pub fn push(&mut self, a: A) {
+    self.tail.push(a)
+  }
+
+  /// Number of values, never 0
+  #[allow(clippy::len_without_is_empty)]
+  pub fn len(&self) -> usize {
+    self.tail.len() + 1
+  }
+
+  /// Iterate over the values from head to last
+  pub fn iter(&self) -> impl Iterator<Item = &A> {
+    Foldable::iter(self)
+  }
+}
+
+impl<A> From<NonEmpty<A>> for Vec<A> {
+  fn from(n: NonEmpty<A>) -> Self {
+    n.into_vec()
+  }
+}
+
+/// Fails with the original Vec when it is empty
+impl<A> TryFrom<Vec<A>> for NonEmpty<A> {
+  type Error = Vec<A>;
+  fn try_from(mut v: Vec<A>) -> Result<Self, Vec<A>> {
+    if v.is_empty() {
+      Err(v)
+    } else {
+      let head = v.remove(0);
+      Ok(NonEmpty { head, tail: v })
+    }
+  }
+}
+
+impl<A> Functor for NonEmpty<A> {
+  fn fmap<B, F>(&self, f: F) -> NonEmpty<B> where F: Fn(&A) -> B {
+    NonEmpty { head: f(&self.head), tail: self.tail.iter().map(f).collect() }
+  }
+}
+
+/// Applies every function to every value, like `bind`
+impl<A> Applicative for NonEmpty<A> {
+  fn pure_(a: A) -> Self {
+    NonEmpty::new(a)
+  }
+
+  fn ap<B, F>(&self, of: NonEmpty<F>) -> NonEmpty<B> where F: Fn(&A) -> B {
+    self.bind(|a| of.fmap(|f| f(a)))
+  }
+}
+
+impl<A> Monad for NonEmpty<A> {
+  fn bind<B, F>(&self, mut f: F) -> NonEmpty<B> where F: FnMut(&A) -> NonEmpty<B> {
+    let mut r = f(&self.head);
+    for a in self.tail.iter() {
+      r.tail.extend(f(a).into_vec());
+    }
+    r
+  }
+}
+
+/// `extend` runs on every non-empty suffix, the head of each suffix is its position
+impl<A: Clone> Comonad for NonEmpty<A> {
+  fn extract(&self) -> A {
+    self.head.clone()
+  }
+
+  fn extend<B: 'static, F>(&self, f: F) -> NonEmpty<B> where F: Fn(&Self) -> B + 'static {
+    let mut suffix = self.clone();
+    let mut r = NonEmpty::new(f(&suffix));
+    while !suffix.tail.is_empty() {
+      suffix = NonEmpty { head: suffix.tail.remove(0), tail: suffix.tail };
+      r.push(f(&suffix));
+    }
+    r
+  }
+}
+
+impl<A> Foldable for NonEmpty<A> {
+  type Iter<'a> = std::iter::Chain<std::iter::Once<&'a A>, std::slice::Iter<'a, A>> where A: 'a;
+  fn iter(&self) -> Self::Iter<'_> {
+    std::iter::once(&self.head).chain(self.tail.iter())
+  }
+}
+
+impl<A> Foldable1 for NonEmpty<A> {
+  fn reduce_left_map<'a, B, G, F>(&'a self, g: G, f: F) -> B where G: FnOnce(&'a A) -> B, F: FnMut(B, &'a A) -> B {
+    self.tail.iter().fold(g(&self.head), f)
+  }
+}
+
+impl<A: Clone> Semigroup for NonEmpty<A> {
+  fn mappend(&self, other: &Self) -> Self {
+    let mut r = self.clone();
+    r.tail.extend(other.iter().cloned());
+    r
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::{Applicative, Functor, Monad, Semigroup};
+  use crate::Foldable::*;
+  use crate::semigroup::{Max, Min};
+  use super::NonEmpty;
+
+  #[cfg(test)]
+  mod laws {
+    use crate::{Applicative, Functor, Monad};
+    use super::NonEmpty;
+
+    fn nel(head: i32, tail: Vec<i32>) -> NonEmpty<i32> { NonEmpty { head, tail } }
+    fn f(a: &i32) -> NonEmpty<i32> { nel(*a, vec![a * 10]) }
+    fn g(a: &i32) -> NonEmpty<i32> { nel(a + 1, vec![]) }
+
+    #[test]
+    fn functor() {
+      let n = nel(1, vec![2, 3]);
+      assert_eq!(n, n.fmap(|x| *x));
+      assert_eq!(n.fmap(|x| compose!(|x| x + 2, |x| x * 5)(x)), n.fmap(|x| x + 2).fmap(|x| x * 5));
+    }
+
+    #[test]
+    fn applicative() {
+      let n = nel(1, vec![2, 3]);
+      assert_eq!(n, n.ap(NonEmpty::<fn(&i32) -> i32>::pure_(|x| *x)));
+      assert_eq!(NonEmpty::pure_(3), NonEmpty::pure_(2).ap(NonEmpty::<fn(&i32) -> i32>::pure_(|x| x + 1)));
+      assert_eq!(n.bind(|a| NonEmpty::pure_(a * 2)), n.ap(NonEmpty::<fn(&i32) -> i32>::pure_(|x| x * 2)));
+    }
+
+    #[test]
+    fn monad() {
+      let n = nel(1, vec![2, 3]);
+      assert_eq!(f(&4), NonEmpty::pure_(4).bind(f));
+      assert_eq!(n, n.bind(|a| NonEmpty::pure_(*a)));
+      assert_eq!(n.bind(f).bind(g), n.bind(|a| f(a).bind(g)));
+    }
+
+    #[test]
+    fn comonad() {
+      use crate::Comonad;
+      let sum = |w: &NonEmpty<i32>| w.head + w.tail.iter().sum::<i32>();
+      let len = |w: &NonEmpty<i32>| w.len() as i32;
+      for n in [nel(1, vec![2, 3]), nel(4, vec![])] {
+        assert_eq!(n, n.extend(|w| w.extract()));
+        assert_eq!(sum(&n), n.extend(sum).extract());
+        assert_eq!(n.extend(len).extend(sum), n.extend(move |w| sum(&w.extend(len))));
+      }
+    }
+  }
+
+  #[test]
+  fn from_vec() {
+    assert_eq!(None, NonEmpty::<i32>::from_vec(vec![]));
+    assert_eq!(Some(NonEmpty::new(1)), NonEmpty::from_vec(vec![1]));
+  }
+
+  #[test]
+  fn into_vec() {
+    assert_eq!(vec![1, 2, 3], NonEmpty { head: 1, tail: vec![2, 3] }.into_vec());
+  }
+
+  #[test]
+  fn conversions() {
+    let v: Vec<i32> = NonEmpty { head: 1, tail: vec![2] }.into();
+    assert_eq!(vec![1, 2], v);
+    assert_eq!(Ok(NonEmpty::new(1)), NonEmpty::try_from(vec![1]));
+    assert_eq!(Err(vec![]), NonEmpty::<i32>::try_from(vec![]));
+  }
+
+  #[test]
+  fn functor_applicative_monad() {
+    let n = NonEmpty { head: 1, tail: vec![2] };
+    assert_eq!(NonEmpty { head: 2, tail: vec![4] }, n.fmap(|a| a * 2));
+    let fs = NonEmpty { head: (|a: &i32| a + 10) as fn(&i32) -> i32, tail: vec![|a: &i32| a * 10] };
+    assert_eq!(NonEmpty { head: 11, tail: vec![10, 12, 20] }, n.ap(fs));
+    assert_eq!(NonEmpty { head: 1, tail: vec![-1, 2, -2] }, n.bind(|a| NonEmpty { head: *a, tail: vec![-a] }));
+  }
+
+  #[test]
+  fn foldable1() {
+    let n = NonEmpty { head: 3, tail: vec![1, 4, 1, 5] };
+    assert_eq!(14, n.reduce());
+    assert_eq!(Max(5), n.reduce_map(|&a| Max(a)));
+    assert_eq!(Min(1), n.reduce_map(|&a| Min(a)));
+    assert_eq!((&3, &5), (n.head(), n.last()));
+    assert_eq!((&5, &1), (n.max(), n.min()));
+    assert_eq!(&7, NonEmpty::new(7).max());
+    assert_eq!(vec![3, 1, 4, 1, 5], n.fold_right(vec![], |&a, mut b: Vec<i32>| { b.insert(0, a); b }));
+    assert_eq!(14, n.fold_left(0, |b, a| a + b));
+  }
+
+  #[test]
+  fn mappend() {
+    let a = NonEmpty { head: 1, tail: vec![2] };
+    let b = NonEmpty::new(3);
+    assert_eq!(NonEmpty { head: 1, tail: vec![2, 3] }, a.mappend(&b));
+    assert_eq!(a.mappend(&b.mappend(&a)), a.mappend(&b).mappend(&a));
+  }
+}
@@ -0,0 +1,56 @@
+//!
+//! Profunctor implementations and tests
+//!
+
+use crate::Profunctor;
+use funlib_macros::compose_two;
+
+impl<A: 'static, R: 'static> Profunctor for Box<dyn Fn(A) -> R> {
+  fn dimap<C: 'static, D: 'static, F, G>(self, f: F, g: G) -> Box<dyn Fn(C) -> D>
+    where F: Fn(C) -> A + 'static, G: Fn(R) -> D + 'static {
+    Box::new(compose_two(compose_two(f, self), g))
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::Profunctor;
+
+  #[cfg(test)]
+  mod laws {
+    use crate::Profunctor;
+
+    fn double() -> Box<dyn Fn(i32) -> i32> { Box::new(|i| i * 2) }
+
+    #[test]
+    fn identity() {
+      let h = double().dimap(|a: i32| a, |b: i32| b);
+      for i in -3..3 {
+        assert_eq!(double()(i), h(i));
+      }
+    }
+
+    #[test]
+    fn composition() {
+      let (f1, f2) = (|s: String| s.len() as i32, |i: u8| "x".repeat(i as usize));
+      let (g1, g2) = (|i: i32| i + 1, |i: i32| i.to_string());
+      let composed = double().dimap(move |c: u8| f1(f2(c)), move |b: i32| g2(g1(b)));
+      let chained = double().dimap(f1, g1).dimap(f2, g2);
+      let split = double().lmap(f1).rmap(g1).lmap(f2).rmap(g2);
+      for i in 0..5u8 {
+        assert_eq!(composed(i), chained(i));
+        assert_eq!(composed(i), split(i));
+      }
+    }
+  }
+
+  #[test]
+  fn boxed_function() {
+    let parse: Box<dyn Fn(String) -> Option<i32>> = Box::new(|s| s.trim().parse().ok());
+    let parse_or_zero = parse.dimap(|s: &str| s.to_string(), |o| o.unwrap_or(0));
+    assert_eq!(42, parse_or_zero(" 42 "));
+    assert_eq!(0, parse_or_zero("nope"));
+    let len: Box<dyn Fn(String) -> usize> = Box::new(|s| s.len());
+    assert_eq!("5", len.rmap(|n| n.to_string())("hello".to_string()));
+  }
+}
@@ -0,0 +1,216 @@
+//!
+//! Reader implementations and tests
+//!
+
+use crate::Kind;
+use std::rc::Rc;
+
+hkt!(Reader<R, _>);
+
+/// A computation that needs an environment of type R to produce an A
+///
+/// Readers are composed with `fmap`, `ap` and `bind` and run once with `run`.
+/// These are inherent methods instead of the `Functor`, `Applicative` and `Monad`
+/// traits, because a Reader keeps its functions until it is run and the trait
+/// methods accept functions that may not live that long.
+///
+/// # Examples
+///
+/// ```
+/// use funlib::reader::Reader;
+/// struct Config { name: String, verbose: bool }
+/// let greeting = Reader::asks(|c: &Config| format!("hello {}", c.name));
+/// let message = greeting.bind(|g| {
+///   let g = g.clone();
+///   Reader::asks(move |c: &Config| if c.verbose { format!("{}, welcome back", g) } else { g.clone() })
+/// });
+/// assert_eq!("hello ann, welcome back", message.run(&Config { name: "ann".to_string(), verbose: true }));
+/// assert_eq!("hello bob", message.run(&Config { name: "bob".to_string(), verbose: false }));
+/// ```
+pub struct Reader<R, A>(Rc<dyn Fn(&R) -> A>);
+
+impl<R, A> Reader<R, A> {
+  /// Create a Reader from a function of the environment, the same as `asks`
+  pub fn new<F>(f: F) -> Self where F: Fn(&R) -> A + 'static {
+    Reader(Rc::new(f))
+  }
+
+  /// A value computed from the environment
+  pub fn asks<F>(f: F) -> Self where F: Fn(&R) -> A + 'static {
+    Reader::new(f)
+  }
+
+  /// Run the computation with an environment
+  pub fn run(&self, r: &R) -> A {
+    (self.0)(r)
+  }
+}
+
+impl<R: 'static, A: 'static> Reader<R, A> {
+  /// Run the computation with a changed environment
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use funlib::reader::Reader;
+  /// let depth = Reader::asks(|d: &u32| *d);
+  /// assert_eq!(3, depth.local(|d| d + 1).run(&2));
+  /// ```
+  pub fn local<F>(&self, f: F) -> Self where F: Fn(&R) -> R + 'static {
+    let r = self.clone();
+    Reader::new(move |e| r.run(&f(e)))
+  }
+
+  /// A Reader that ignores the environment
+  pub fn pure_(a: A) -> Self where A: Clone {
+    Reader::new(move |_| a.clone())
+  }
+
+  /// Map the result
+  pub fn fmap<B: 'static, F>(&self, f: F) -> Reader<R, B> where F: Fn(&A) -> B + 'static {
+    let r = self.clone();
+    Reader::new(move |e| f(&r.run(e)))
+  }
+
+  /// Apply the function computed by another Reader to the result, both share the environment
+  pub fn ap<B: 'static, F>(&self, of: Reader<R, F>) -> Reader<R, B> where F: Fn(&A) -> B + 'static {
+    let r = self.clone();
+    Reader::new(move |e| of.run(e)(&r.run(e)))
+  }
+
+  /// Continue with a Reader chosen by the result, both share the environment
+  pub fn bind<B: 'static, F>(&self, f: F) -> Reader<R, B> where F: Fn(&A) -> Reader<R, B> + 'static {
+    let r = self.clone();
+    Reader::new(move |e| f(&r.run(e)).run(e))
+  }
+}
+
+impl<R: Clone + 'static> Reader<R, R> {
+  /// The environment itself
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use funlib::reader::Reader;
+  /// assert_eq!(4, Reader::ask().fmap(|n: &i32| n * 2).run(&2));
+  /// ```
+  pub fn ask() -> Self {
+    Reader::new(|r: &R| r.clone())
+  }
+}
+
+impl<R, A> Clone for Reader<R, A> {
+  fn clone(&self) -> Self {
+    Reader(self.0.clone())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::Reader;
+  use std::cell::RefCell;
+  use std::collections::HashMap;
+  use std::rc::Rc;
+
+  #[cfg(test)]
+  mod laws {
+    use super::Reader;
+
+    type Count = Reader<i32, i32>;
+
+    fn same<A: PartialEq + std::fmt::Debug + 'static>(a: &Reader<i32, A>, b: &Reader<i32, A>) {
+      for e in -3..4 {
+        assert_eq!(a.run(&e), b.run(&e));
+      }
+    }
+
+    fn f(a: &i32) -> Count { let a = *a; Reader::asks(move |e| a + e) }
+    fn g(a: &i32) -> Count { let a = *a; Reader::asks(move |e| a * e) }
+
+    #[test]
+    fn functor() {
+      let r: Count = Reader::asks(|e| e * 3);
+      same(&r, &r.fmap(|x| *x));
+      same(&r.fmap(|x| compose!(|x| x + 2, |x| x * 5)(x)), &r.fmap(|x| x + 2).fmap(|x| x * 5));
+    }
+
+    #[test]
+    fn applicative() {
+      let r: Count = Reader::asks(|e| e * 3);
+      same(&r, &r.ap(Reader::pure_((|x| *x) as fn(&i32) -> i32)));
+      same(&Reader::pure_(3), &Reader::pure_(2).ap(Reader::pure_((|x| x + 1) as fn(&i32) -> i32)));
+      let fs: Reader<i32, fn(&i32) -> i32> = Reader::asks(|e| {
+        let dec: fn(&i32) -> i32 = |x| x - 1;
+        let double: fn(&i32) -> i32 = |x| x * 2;
+        if *e > 0 { dec } else { double }
+      });
+      let bound = fs.clone();
+      same(&r.ap(fs), &r.bind(move |a| { let a = *a; bound.fmap(move |f| f(&a)) }));
+    }
+
+    #[test]
+    fn monad() {
+      let r: Count = Reader::asks(|e| e * 3);
+      same(&f(&4), &Reader::pure_(4).bind(f));
+      same(&r, &r.bind(|a| Reader::pure_(*a)));
+      same(&r.bind(f).bind(g), &r.bind(|a| f(a).bind(g)));
+    }
+
+    #[test]
+    fn reader() {
+      let r: Count = Reader::asks(|e| e * 3);
+      same(&Reader::ask().fmap(|e| e * 3), &r);
+      same(&r.local(|e| *e), &r);
+      same(&r.local(|e| e + 1).local(|e| e * 2), &r.local(|e| e * 2 + 1));
+    }
+  }
+
+  /// The services a request handler needs
+  struct Services {
+    users: Rc<dyn UserStore>,
+    greeting: String,
+  }
+
+  trait UserStore {
+    fn name(&self, id: u32) -> Option<String>;
+    fn rename(&self, id: u32, name: &str);
+  }
+
+  /// Stand-in for a database, keeps users in a HashMap
+  struct InMemoryUsers(RefCell<HashMap<u32, String>>);
+
+  impl UserStore for InMemoryUsers {
+    fn name(&self, id: u32) -> Option<String> {
+      self.0.borrow().get(&id).cloned()
+    }
+    fn rename(&self, id: u32, name: &str) {
+      self.0.borrow_mut().insert(id, name.to_string());
+    }
+  }
+
+  fn greet(id: u32) -> Reader<Services, String> {
+    Reader::asks(move |s: &Services| s.users.name(id)).bind(|name| {
+      let name = name.clone().unwrap_or_else(|| "stranger".to_string());
+      Reader::asks(move |s: &Services| format!("{} {}", s.greeting, name))
+    })
+  }
+
+  fn rename_and_greet(id: u32, name: &'static str) -> Reader<Services, String> {
+    Reader::asks(move |s: &Services| s.users.rename(id, name)).bind(move |_| greet(id))
+  }
+
+  #[test]
+  fn in_memory_environment() {
+    let users = InMemoryUsers(RefCell::new(HashMap::from([(1, "ann".to_string())])));
+    let services = Services { users: Rc::new(users), greeting: "hello".to_string() };
+    let program = greet(1).bind(|first| {
+      let first = first.clone();
+      rename_and_greet(2, "bob").fmap(move |second| vec![first.clone(), second.clone()])
+    });
+    assert_eq!(vec!["hello ann", "hello bob"], program.run(&services));
+    assert_eq!(Some("bob".to_string()), services.users.name(2));
+    let formal = program.local(|s| Services { users: s.users.clone(), greeting: "good day".to_string() });
+    assert_eq!(vec!["good day ann", "good day bob"], formal.run(&services));
+    assert_eq!("hello stranger", greet(3).run(&services));
+  }
+}
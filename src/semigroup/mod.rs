@@ -3,7 +3,15 @@
 //!
 
 use crate::Semigroup;
+use std::borrow::Cow;
+use std::cmp::Ordering;
 use std::boxed::Box;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, LinkedList, VecDeque};
+use std::hash::Hash;
+use std::fmt;
+use std::num::{Saturating, Wrapping};
+use std::ops::Add;
+use std::rc::Rc;
 
 macro_rules! semigroup {
   ($t:ident, $a:ident, $b:ident, $v:expr) => {
@@ -15,42 +23,583 @@ macro_rules! semigroup {
   }
 }
 
-impl<'a> Semigroup for &'a str {
+impl Semigroup for String {
   fn mappend(&self, other: &Self) -> Self {
-    Box::leak(format!("{}{}",*self, *other).into_boxed_str())
+    let mut r = String::with_capacity(self.len() + other.len());
+    r.push_str(self);
+    r.push_str(other);
+    r
   }
 }
 
-semigroup!(i8, self, o, self + o);
-semigroup!(i16, self, o, self + o);
-semigroup!(i32, self, o, self + o);
-semigroup!(i64, self, o, self + o);
-semigroup!(u8, self, o, self + o);
-semigroup!(u16, self, o, self + o);
-semigroup!(u32, self, o, self + o);
-semigroup!(u64, self, o, self + o);
+/// Borrowed values are kept as they are when the other side is empty, otherwise a new String is created
+impl Semigroup for Cow<'_, str> {
+  fn mappend(&self, other: &Self) -> Self {
+    if other.is_empty() {
+      self.clone()
+    } else if self.is_empty() {
+      other.clone()
+    } else {
+      Cow::Owned(self.to_string().mappend(&other.to_string()))
+    }
+  }
+}
+
+// Integers wrap on overflow so that debug and release builds agree, use `Checked` or `Saturating` to detect or clamp it
+semigroup!(i8, self, o, self.wrapping_add(*o));
+semigroup!(i16, self, o, self.wrapping_add(*o));
+semigroup!(i32, self, o, self.wrapping_add(*o));
+semigroup!(i64, self, o, self.wrapping_add(*o));
+semigroup!(i128, self, o, self.wrapping_add(*o));
+semigroup!(isize, self, o, self.wrapping_add(*o));
+semigroup!(u8, self, o, self.wrapping_add(*o));
+semigroup!(u16, self, o, self.wrapping_add(*o));
+semigroup!(u32, self, o, self.wrapping_add(*o));
+semigroup!(u64, self, o, self.wrapping_add(*o));
+semigroup!(u128, self, o, self.wrapping_add(*o));
+semigroup!(usize, self, o, self.wrapping_add(*o));
 semigroup!(f32, self, o, self + o);
 semigroup!(f64, self, o, self + o);
 
+impl Semigroup for () {
+  fn mappend(&self, _: &Self) -> Self {}
+}
+
+/// Tuples combine element-wise
+macro_rules! semigroup_tuple {
+  ($($t:ident $i:tt),+) => {
+    impl<$($t: Semigroup),+> Semigroup for ($($t,)+) {
+      fn mappend(&self, o: &Self) -> Self {
+        ($(self.$i.mappend(&o.$i),)+)
+      }
+    }
+  }
+}
+
+semigroup_tuple!(A 0);
+semigroup_tuple!(A 0, B 1);
+semigroup_tuple!(A 0, B 1, C 2);
+semigroup_tuple!(A 0, B 1, C 2, D 3);
+semigroup_tuple!(A 0, B 1, C 2, D 3, E 4);
+semigroup_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+semigroup_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+semigroup_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+semigroup_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+semigroup_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+semigroup_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+semigroup_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+
 impl<A: Semigroup> Semigroup for Option<A> {
   fn mappend(&self, other: &Self) -> Self {
     match (self, other) {
-      (&None, b) => b.clone(),
-      (a, &None) => a.clone(),
-      (&Some(ref a), &Some(ref b)) => Some(a.mappend(b)),
+      (None, b) => b.clone(),
+      (a, None) => a.clone(),
+      (Some(a), Some(b)) => Some(a.mappend(b)),
     }
   }
 }
 
+impl<A: Clone> Semigroup for Vec<A> {
+  fn mappend(&self, other: &Self) -> Self {
+    let mut r = self.clone();
+    r.extend_from_slice(other);
+    r
+  }
+}
+
+impl<A: Clone> Semigroup for VecDeque<A> {
+  fn mappend(&self, other: &Self) -> Self {
+    let mut r = self.clone();
+    r.extend(other.iter().cloned());
+    r
+  }
+}
+
+impl<A: Clone> Semigroup for LinkedList<A> {
+  fn mappend(&self, other: &Self) -> Self {
+    let mut r = self.clone();
+    r.extend(other.iter().cloned());
+    r
+  }
+}
+
+impl<A: Eq + Hash + Clone> Semigroup for HashSet<A> {
+  fn mappend(&self, other: &Self) -> Self {
+    self.union(other).cloned().collect()
+  }
+}
+
+impl<A: Ord + Clone> Semigroup for BTreeSet<A> {
+  fn mappend(&self, other: &Self) -> Self {
+    self.union(other).cloned().collect()
+  }
+}
+
+/// Union of the maps, values found under the same key are combined with `mappend`
+impl<K: Eq + Hash + Clone, V: Semigroup> Semigroup for HashMap<K, V> {
+  fn mappend(&self, other: &Self) -> Self {
+    let mut r = self.clone();
+    for (k, v) in other {
+      let merged = match r.get(k) {
+        Some(a) => a.mappend(v),
+        None => v.clone(),
+      };
+      r.insert(k.clone(), merged);
+    }
+    r
+  }
+}
+
+/// Union of the maps, values found under the same key are combined with `mappend`
+impl<K: Ord + Clone, V: Semigroup> Semigroup for BTreeMap<K, V> {
+  fn mappend(&self, other: &Self) -> Self {
+    let mut r = self.clone();
+    for (k, v) in other {
+      let merged = match r.get(k) {
+        Some(a) => a.mappend(v),
+        None => v.clone(),
+      };
+      r.insert(k.clone(), merged);
+    }
+    r
+  }
+}
+
 impl <A: Semigroup> Semigroup for Box<A> {
   fn mappend(&self, other: &Self) -> Self {
     Box::new(self.as_ref().mappend(other.as_ref()))
   }
 }
 
+/// Combines the `Ok` values when both sides succeed, otherwise the errors are combined
+///
+/// # Examples
+///
+/// ```
+/// use funlib::Semigroup;
+/// let a: Result<i32, u32> = Ok(1);
+/// let e: Result<i32, u32> = Err(1);
+/// assert_eq!(Ok(3), a.mappend(&Ok(2)));
+/// assert_eq!(Err(1), a.mappend(&e));
+/// assert_eq!(Err(2), e.mappend(&e));
+/// ```
+impl<A: Semigroup, E: Semigroup> Semigroup for Result<A, E> {
+  fn mappend(&self, other: &Self) -> Self {
+    match (self, other) {
+      (Ok(a), Ok(b)) => Ok(a.mappend(b)),
+      (Err(a), Err(b)) => Err(a.mappend(b)),
+      (Err(e), _) | (_, Err(e)) => Err(e.clone()),
+    }
+  }
+}
+
+/// Result wrapper whose Semigroup keeps the first `Ok` and combines the errors when every value failed
+///
+/// # Examples
+///
+/// ```
+/// use funlib::Semigroup;
+/// use funlib::semigroup::FirstOk;
+/// let a: FirstOk<i32, u32> = FirstOk(Err(1));
+/// let b: FirstOk<i32, u32> = FirstOk(Ok(2));
+/// let c: FirstOk<i32, u32> = FirstOk(Err(3));
+/// assert_eq!(FirstOk(Ok(2)), a.mappend(&b).mappend(&c));
+/// assert_eq!(FirstOk(Err(4)), a.mappend(&c));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FirstOk<A, E>(pub Result<A, E>);
+
+impl<A: Clone, E: Semigroup> Semigroup for FirstOk<A, E> {
+  fn mappend(&self, other: &Self) -> Self {
+    match (&self.0, &other.0) {
+      (Ok(_), _) => self.clone(),
+      (Err(a), Err(b)) => FirstOk(Err(a.mappend(b))),
+      (Err(_), Ok(_)) => other.clone(),
+    }
+  }
+}
+
+impl<A> Semigroup for Wrapping<A> where Wrapping<A>: Add<Output = Wrapping<A>> + Clone {
+  fn mappend(&self, other: &Self) -> Self {
+    self.clone() + other.clone()
+  }
+}
+
+// Saturating addition is only associative without mixed signs, so only unsigned integers get an instance
+macro_rules! saturating {
+  ($t:ident) => {
+    impl Semigroup for Saturating<$t> {
+      fn mappend(&self, other: &Self) -> Self {
+        *self + *other
+      }
+    }
+  }
+}
+
+saturating!(u8);
+saturating!(u16);
+saturating!(u32);
+saturating!(u64);
+saturating!(u128);
+saturating!(usize);
+
+/// Semigroup that adds integers and records overflow as `Checked(None)`
+///
+/// # Examples
+///
+/// ```
+/// use funlib::Foldable::*;
+/// use funlib::semigroup::Checked;
+/// assert_eq!(Checked(Some(250u8)), vec![100u8, 100, 50].fold_map(|&a| Checked::new(a)));
+/// assert!(vec![100u8, 100, 100].fold_map(|&a| Checked::new(a)).is_overflow());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Checked<A>(pub Option<A>);
+
+impl<A> Checked<A> {
+  /// Wraps a value that has not overflowed
+  pub fn new(a: A) -> Self {
+    Checked(Some(a))
+  }
+  /// Whether an overflow happened while combining
+  pub fn is_overflow(&self) -> bool {
+    self.0.is_none()
+  }
+}
+
+macro_rules! checked {
+  ($t:ident) => {
+    impl Semigroup for Checked<$t> {
+      fn mappend(&self, other: &Self) -> Self {
+        match (self.0, other.0) {
+          (Some(a), Some(b)) => Checked(a.checked_add(b)),
+          _ => Checked(None),
+        }
+      }
+    }
+  }
+}
+
+checked!(i8);
+checked!(i16);
+checked!(i32);
+checked!(i64);
+checked!(i128);
+checked!(isize);
+checked!(u8);
+checked!(u16);
+checked!(u32);
+checked!(u64);
+checked!(u128);
+checked!(usize);
+
+/// Semigroup that adds the values
+///
+/// # Examples
+///
+/// ```
+/// use funlib::Foldable::*;
+/// use funlib::semigroup::Sum;
+/// assert_eq!(Sum(10), vec![1, 2, 3, 4].fold_map(|&a| Sum(a)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Sum<A>(pub A);
+
+
+/// Semigroup that multiplies the values
+///
+/// # Examples
+///
+/// ```
+/// use funlib::Foldable::*;
+/// use funlib::semigroup::Product;
+/// assert_eq!(Product(24), vec![1, 2, 3, 4].fold_map(|&a| Product(a)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Product<A>(pub A);
+
+
+macro_rules! sum_product {
+  ($t:ident, $a:ident, $b:ident, $sum:expr, $product:expr) => {
+    impl Semigroup for Sum<$t> {
+      fn mappend($a: &Self, $b: &Self) -> Self {
+        Sum($sum)
+      }
+    }
+    impl Semigroup for Product<$t> {
+      fn mappend($a: &Self, $b: &Self) -> Self {
+        Product($product)
+      }
+    }
+  }
+}
+
+// Integers wrap on overflow like their own Semigroup instances
+sum_product!(i8, self, o, self.0.wrapping_add(o.0), self.0.wrapping_mul(o.0));
+sum_product!(i16, self, o, self.0.wrapping_add(o.0), self.0.wrapping_mul(o.0));
+sum_product!(i32, self, o, self.0.wrapping_add(o.0), self.0.wrapping_mul(o.0));
+sum_product!(i64, self, o, self.0.wrapping_add(o.0), self.0.wrapping_mul(o.0));
+sum_product!(i128, self, o, self.0.wrapping_add(o.0), self.0.wrapping_mul(o.0));
+sum_product!(isize, self, o, self.0.wrapping_add(o.0), self.0.wrapping_mul(o.0));
+sum_product!(u8, self, o, self.0.wrapping_add(o.0), self.0.wrapping_mul(o.0));
+sum_product!(u16, self, o, self.0.wrapping_add(o.0), self.0.wrapping_mul(o.0));
+sum_product!(u32, self, o, self.0.wrapping_add(o.0), self.0.wrapping_mul(o.0));
+sum_product!(u64, self, o, self.0.wrapping_add(o.0), self.0.wrapping_mul(o.0));
+sum_product!(u128, self, o, self.0.wrapping_add(o.0), self.0.wrapping_mul(o.0));
+sum_product!(usize, self, o, self.0.wrapping_add(o.0), self.0.wrapping_mul(o.0));
+sum_product!(f32, self, o, self.0 + o.0, self.0 * o.0);
+sum_product!(f64, self, o, self.0 + o.0, self.0 * o.0);
+
+/// Semigroup that keeps the smallest value
+///
+/// # Examples
+///
+/// ```
+/// use funlib::Foldable::*;
+/// use funlib::semigroup::Min;
+/// assert_eq!(Min(1), vec![3, 1, 4].fold_map(|&a| Min(a)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Min<A>(pub A);
+
+impl<A: PartialOrd + Clone> Semigroup for Min<A> {
+  fn mappend(&self, other: &Self) -> Self {
+    if other.0 < self.0 { other.clone() } else { self.clone() }
+  }
+}
+
+/// Semigroup that keeps the largest value
+///
+/// # Examples
+///
+/// ```
+/// use funlib::Foldable::*;
+/// use funlib::semigroup::Max;
+/// assert_eq!(Max(4), vec![3, 1, 4].fold_map(|&a| Max(a)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Max<A>(pub A);
+
+impl<A: PartialOrd + Clone> Semigroup for Max<A> {
+  fn mappend(&self, other: &Self) -> Self {
+    if other.0 > self.0 { other.clone() } else { self.clone() }
+  }
+}
+
+/// Semigroup that keeps the first Some value
+///
+/// # Examples
+///
+/// ```
+/// use funlib::Foldable::*;
+/// use funlib::semigroup::First;
+/// assert_eq!(First(Some(3)), vec![None, Some(3), Some(4)].fold_map(|&a| First(a)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct First<A>(pub Option<A>);
+
+impl<A: Clone> Semigroup for First<A> {
+  fn mappend(&self, other: &Self) -> Self {
+    match self.0 {
+      Some(_) => self.clone(),
+      None => other.clone(),
+    }
+  }
+}
+
+/// Semigroup that keeps the last Some value
+///
+/// # Examples
+///
+/// ```
+/// use funlib::Foldable::*;
+/// use funlib::semigroup::Last;
+/// assert_eq!(Last(Some(4)), vec![None, Some(3), Some(4), None].fold_map(|&a| Last(a)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Last<A>(pub Option<A>);
+
+impl<A: Clone> Semigroup for Last<A> {
+  fn mappend(&self, other: &Self) -> Self {
+    match other.0 {
+      Some(_) => other.clone(),
+      None => self.clone(),
+    }
+  }
+}
+
+/// Semigroup that is true when any of the values are true
+///
+/// # Examples
+///
+/// ```
+/// use funlib::Foldable::*;
+/// use funlib::semigroup::Any;
+/// assert_eq!(Any(true), vec![1, 2, 3].fold_map(|&a| Any(a > 2)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Any(pub bool);
+
+impl Semigroup for Any {
+  fn mappend(&self, other: &Self) -> Self {
+    Any(self.0 || other.0)
+  }
+}
+
+/// Semigroup that is true when all of the values are true
+///
+/// # Examples
+///
+/// ```
+/// use funlib::Foldable::*;
+/// use funlib::semigroup::All;
+/// assert_eq!(All(false), vec![1, 2, 3].fold_map(|&a| All(a > 2)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct All(pub bool);
+
+impl Semigroup for All {
+  fn mappend(&self, other: &Self) -> Self {
+    All(self.0 && other.0)
+  }
+}
+
+/// Semigroup that combines the inner values in the opposite order
+///
+/// # Examples
+///
+/// ```
+/// use funlib::Semigroup;
+/// use funlib::semigroup::{Dual, First};
+/// assert_eq!(Dual(First(Some(2))), Dual(First(Some(1))).mappend(&Dual(First(Some(2)))));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Dual<A>(pub A);
+
+impl<A: Semigroup> Semigroup for Dual<A> {
+  fn mappend(&self, other: &Self) -> Self {
+    Dual(other.0.mappend(&self.0))
+  }
+}
+
+/// Semigroup that adds floating point numbers with Neumaier compensated summation
+///
+/// The rounding error of every addition is tracked separately, so long sums keep
+/// the precision that a plain `+` loses.
+///
+/// # Examples
+///
+/// ```
+/// use funlib::Foldable::*;
+/// use funlib::semigroup::KahanSum;
+/// let v = vec![1.0, 1e100, 1.0, -1e100];
+/// assert_eq!(2.0, v.fold_map(|&a| KahanSum::new(a)).value());
+/// assert_eq!(0.0, v.concat());
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct KahanSum<A> {
+  sum: A,
+  compensation: A,
+}
+
+impl<A: Add<Output = A> + Copy + Default> KahanSum<A> {
+  /// Starts a sum from a single value
+  pub fn new(a: A) -> Self {
+    KahanSum { sum: a, compensation: A::default() }
+  }
+  /// The compensated total
+  pub fn value(&self) -> A {
+    self.sum + self.compensation
+  }
+}
+
+/// Sums are equal when their totals are, however the rounding error was split
+impl<A: Add<Output = A> + Copy + Default + PartialEq> PartialEq for KahanSum<A> {
+  fn eq(&self, other: &Self) -> bool {
+    self.value() == other.value()
+  }
+}
+
+impl<A: Add<Output = A> + Copy + Default + PartialOrd> PartialOrd for KahanSum<A> {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    self.value().partial_cmp(&other.value())
+  }
+}
+
+macro_rules! kahan {
+  ($t:ident) => {
+    impl Semigroup for KahanSum<$t> {
+      fn mappend(&self, other: &Self) -> Self {
+        let t = self.sum + other.sum;
+        let error = if self.sum.abs() >= other.sum.abs() {
+          (self.sum - t) + other.sum
+        } else {
+          (other.sum - t) + self.sum
+        };
+        KahanSum { sum: t, compensation: self.compensation + other.compensation + error }
+      }
+    }
+  }
+}
+
+kahan!(f32);
+kahan!(f64);
+
+/// Semigroup of functions from a type to itself, combined with function composition
+///
+/// `f.mappend(&g)` runs `g` first and then `f`.
+///
+/// # Examples
+///
+/// ```
+/// use funlib::Foldable::*;
+/// use funlib::semigroup::Endo;
+/// let add1 = Endo::new(|i: i32| i + 1);
+/// let double = Endo::new(|i: i32| i * 2);
+/// assert_eq!(3, funlib::Semigroup::mappend(&add1, &double).run(1));
+/// let steps = vec![1, 2, 3].fold_map(|&a| Endo::new(move |i: i32| i * 10 + a));
+/// assert_eq!(321, steps.run(0));
+/// ```
+pub struct Endo<'a, A>(pub Rc<dyn Fn(A) -> A + 'a>);
+
+impl<'a, A> Endo<'a, A> {
+  /// Wrap a function
+  pub fn new<F>(f: F) -> Self where F: Fn(A) -> A + 'a {
+    Endo(Rc::new(f))
+  }
+
+  /// Run the function
+  pub fn run(&self, a: A) -> A {
+    (self.0)(a)
+  }
+}
+
+impl<A> Clone for Endo<'_, A> {
+  fn clone(&self) -> Self {
+    Endo(self.0.clone())
+  }
+}
+
+impl<A> fmt::Debug for Endo<'_, A> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str("Endo")
+  }
+}
+
+impl<'a, A: 'a> Semigroup for Endo<'a, A> {
+  fn mappend(&self, other: &Self) -> Self {
+    let (f, g) = (self.0.clone(), other.0.clone());
+    Endo::new(funlib_macros::compose_two(move |a| g(a), move |a| f(a)))
+  }
+}
+
 #[cfg(test)]
 mod test {
   use crate::Semigroup;
+  use super::{All, Any, Checked, Dual, Endo, First, FirstOk, KahanSum, Last, Max, Min, Product, Sum};
+  use std::borrow::Cow;
+  use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, LinkedList, VecDeque};
+  use std::num::{Saturating, Wrapping};
 
   #[test]
   fn i32() {
@@ -95,8 +644,133 @@ mod test {
   }
 
   #[test]
-  fn str_test() {
-    assert_eq!("hello, world", "hello".mappend(&", world"));
+  fn vec() {
+    assert_eq!(vec![1, 2, 3], vec![1].mappend(&vec![2, 3]));
+    assert_eq!(Dual(vec![3, 1, 2]), Dual(vec![1, 2]).mappend(&Dual(vec![3])));
+  }
+
+  #[test]
+  fn tuples() {
+    assert_eq!((), ().mappend(&()));
+    assert_eq!((Sum(3), Max(2), vec![1, 2]), (Sum(1), Max(2), vec![1]).mappend(&(Sum(2), Max(1), vec![2])));
+    let a = (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12);
+    assert_eq!((2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24), a.mappend(&a));
+  }
+
+  #[test]
+  fn vec_deque() {
+    let a: VecDeque<i32> = vec![1, 2].into();
+    let b: VecDeque<i32> = vec![3].into();
+    assert_eq!(VecDeque::from(vec![1, 2, 3]), a.mappend(&b));
+  }
+
+  #[test]
+  fn linked_list() {
+    let a: LinkedList<i32> = vec![1, 2].into_iter().collect();
+    let b: LinkedList<i32> = vec![3].into_iter().collect();
+    assert_eq!(vec![1, 2, 3], a.mappend(&b).into_iter().collect::<Vec<_>>());
+  }
+
+  #[test]
+  fn sets() {
+    let a: HashSet<i32> = vec![1, 2].into_iter().collect();
+    let b: HashSet<i32> = vec![2, 3].into_iter().collect();
+    assert_eq!(vec![1, 2, 3].into_iter().collect::<HashSet<_>>(), a.mappend(&b));
+    let a: BTreeSet<i32> = vec![1, 2].into_iter().collect();
+    let b: BTreeSet<i32> = vec![2, 3].into_iter().collect();
+    assert_eq!(vec![1, 2, 3].into_iter().collect::<BTreeSet<_>>(), a.mappend(&b));
+  }
+
+  #[test]
+  fn maps() {
+    let a: HashMap<&str, i32> = vec![("a", 1), ("b", 2)].into_iter().collect();
+    let b: HashMap<&str, i32> = vec![("b", 3), ("c", 4)].into_iter().collect();
+    assert_eq!(vec![("a", 1), ("b", 5), ("c", 4)].into_iter().collect::<HashMap<_, _>>(), a.mappend(&b));
+    let a: BTreeMap<&str, Vec<i32>> = vec![("a", vec![1]), ("b", vec![2])].into_iter().collect();
+    let b: BTreeMap<&str, Vec<i32>> = vec![("b", vec![3])].into_iter().collect();
+    assert_eq!(vec![("a", vec![1]), ("b", vec![2, 3])].into_iter().collect::<BTreeMap<_, _>>(), a.mappend(&b));
+  }
+
+  #[test]
+  fn result_ok() {
+    assert_eq!(Ok::<i32, i32>(3), Ok(1).mappend(&Ok(2)));
+  }
+
+  #[test]
+  fn result_err() {
+    assert_eq!(Err::<i32, i32>(5), Ok(1).mappend(&Err(5)));
+    assert_eq!(Err::<i32, i32>(5), Err(5).mappend(&Ok(1)));
+    assert_eq!(Err::<i32, i32>(7), Err(5).mappend(&Err(2)));
+  }
+
+  #[test]
+  fn first_ok() {
+    assert_eq!(FirstOk(Ok::<i32, i32>(1)), FirstOk(Ok(1)).mappend(&FirstOk(Ok(2))));
+    assert_eq!(FirstOk(Ok::<i32, i32>(2)), FirstOk(Err(1)).mappend(&FirstOk(Ok(2))));
+    assert_eq!(FirstOk(Err::<i32, i32>(3)), FirstOk(Err(1)).mappend(&FirstOk(Err(2))));
+  }
+
+  #[test]
+  fn wrappers() {
+    assert_eq!(Sum(5), Sum(2).mappend(&Sum(3)));
+    assert_eq!(Product(6), Product(2).mappend(&Product(3)));
+    assert_eq!(Min(2), Min(2).mappend(&Min(3)));
+    assert_eq!(Min(1.5), Min(2.0).mappend(&Min(1.5)));
+    assert_eq!(Max(3), Max(2).mappend(&Max(3)));
+    assert_eq!(First(Some(2)), First(Some(2)).mappend(&First(Some(3))));
+    assert_eq!(First(Some(3)), First(None).mappend(&First(Some(3))));
+    assert_eq!(Last(Some(3)), Last(Some(2)).mappend(&Last(Some(3))));
+    assert_eq!(Last(Some(2)), Last(Some(2)).mappend(&Last(None)));
+    assert_eq!(Any(true), Any(false).mappend(&Any(true)));
+    assert_eq!(All(false), All(false).mappend(&All(true)));
+    assert_eq!(Dual(Last(Some(1))), Dual(Last(Some(1))).mappend(&Dual(Last(Some(2)))));
+  }
+
+  #[test]
+  fn overflow() {
+    assert_eq!(44u8, 200u8.mappend(&100));
+    assert_eq!(i128::MIN, i128::MAX.mappend(&1));
+    assert_eq!(0usize, usize::MAX.mappend(&1));
+    assert_eq!(Sum(44u8), Sum(200u8).mappend(&Sum(100)));
+    assert_eq!(Sum(i64::MIN), Sum(i64::MAX).mappend(&Sum(1)));
+    assert_eq!(Product(32u8), Product(200u8).mappend(&Product(100)));
+    assert_eq!(Product(i32::MIN), Product(i32::MIN).mappend(&Product(-1)));
+    assert_eq!(Wrapping(44u8), Wrapping(200u8).mappend(&Wrapping(100)));
+    assert_eq!(Saturating(255u8), Saturating(200u8).mappend(&Saturating(100)));
+    assert_eq!(Saturating(u64::MAX), Saturating(u64::MAX).mappend(&Saturating(1)));
+    assert_eq!(Checked(Some(250u8)), Checked::new(200u8).mappend(&Checked::new(50)));
+    assert_eq!(Checked(None), Checked::new(200u8).mappend(&Checked::new(100)));
+    assert_eq!(Checked(None), Checked(None).mappend(&Checked::new(0u128)));
+  }
+
+  #[test]
+  fn kahan_sum() {
+    assert_eq!(1.0, KahanSum::new(1e16).mappend(&KahanSum::new(1.0)).mappend(&KahanSum::new(-1e16)).value());
+    assert_eq!(2.0f32, KahanSum::new(1.0f32).mappend(&KahanSum::new(1e30)).mappend(&KahanSum::new(1.0)).mappend(&KahanSum::new(-1e30)).value());
+  }
+
+  #[test]
+  fn endo() {
+    let add1 = Endo::new(|i: i32| i + 1);
+    let double = Endo::new(|i: i32| i * 2);
+    assert_eq!(3, add1.mappend(&double).run(1));
+    assert_eq!(4, double.mappend(&add1).run(1));
+    assert_eq!(6, Dual(add1).mappend(&Dual(double)).0.run(2));
+  }
+
+  #[test]
+  fn string() {
+    assert_eq!("hello, world", "hello".to_string().mappend(&", world".to_string()));
+  }
+
+  #[test]
+  fn cow_str() {
+    let hello: Cow<str> = Cow::Borrowed("hello");
+    let world: Cow<str> = Cow::Owned(", world".to_string());
+    let empty: Cow<str> = Cow::Borrowed("");
+    assert_eq!("hello, world", hello.mappend(&world));
+    assert!(matches!(hello.mappend(&empty), Cow::Borrowed("hello")));
+    assert!(matches!(empty.mappend(&hello), Cow::Borrowed("hello")));
   }
 
 }
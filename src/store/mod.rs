@@ -0,0 +1,165 @@
+//!
+//! Store implementations and tests
+//!
+
+use crate::{Comonad, Kind};
+use std::rc::Rc;
+
+hkt!(Store<S, _>);
+
+/// A lookup function together with the position it is focused on
+///
+/// `extend` is lazy, every lookup reruns the functions of all earlier `extend` calls,
+/// use `tabulate` to store the values of a finite range of positions between steps.
+///
+/// # Examples
+///
+/// ```
+/// use funlib::Comonad;
+/// use funlib::store::Store;
+/// let squares = Store::new(|i: &i32| i * i, 3);
+/// assert_eq!(9, squares.extract());
+/// let sums = squares.extend(|w| w.peeks(|i| i - 1) + w.extract());
+/// assert_eq!(13, sums.extract());
+/// assert_eq!(5, sums.peek(&2));
+/// ```
+pub struct Store<S, A> {
+  pos: S,
+  lookup: Rc<dyn Fn(&S) -> A>,
+}
+
+impl<S, A> Store<S, A> {
+  /// Create a Store from a lookup function and a position
+  pub fn new<F>(lookup: F, pos: S) -> Self where F: Fn(&S) -> A + 'static {
+    Store { pos, lookup: Rc::new(lookup) }
+  }
+
+  /// The current position
+  pub fn pos(&self) -> &S {
+    &self.pos
+  }
+
+  /// The value at any position
+  pub fn peek(&self, s: &S) -> A {
+    (self.lookup)(s)
+  }
+
+  /// The value at a position relative to the current one
+  pub fn peeks<F>(&self, f: F) -> A where F: FnOnce(&S) -> S {
+    self.peek(&f(&self.pos))
+  }
+
+  /// Move the focus to a new position
+  pub fn seek(&self, pos: S) -> Self {
+    Store { pos, lookup: self.lookup.clone() }
+  }
+
+  /// Move the focus relative to the current position
+  pub fn seeks<F>(&self, f: F) -> Self where F: FnOnce(&S) -> S {
+    self.seek(f(&self.pos))
+  }
+
+  /// Store the values of the given positions, other positions fall back to the lookup
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use funlib::store::Store;
+  /// let counted = Store::new(|i: &usize| *i, 0).tabulate(0..3);
+  /// assert_eq!(2, counted.peek(&2));
+  /// assert_eq!(7, counted.peek(&7));
+  /// ```
+  pub fn tabulate<I>(&self, positions: I) -> Self where I: IntoIterator<Item = S>, S: Ord + Clone + 'static, A: Clone + 'static {
+    let table: std::collections::BTreeMap<S, A> = positions.into_iter().map(|s| { let a = self.peek(&s); (s, a) }).collect();
+    let lookup = self.lookup.clone();
+    Store::new(move |s| table.get(s).cloned().unwrap_or_else(|| lookup(s)), self.pos.clone())
+  }
+}
+
+impl<S: Clone, A> Clone for Store<S, A> {
+  fn clone(&self) -> Self {
+    Store { pos: self.pos.clone(), lookup: self.lookup.clone() }
+  }
+}
+
+impl<S: Clone + 'static, A: 'static> Comonad for Store<S, A> {
+  fn extract(&self) -> A {
+    self.peek(&self.pos)
+  }
+
+  fn extend<B: 'static, F>(&self, f: F) -> Store<S, B> where F: Fn(&Self) -> B + 'static {
+    let lookup = self.lookup.clone();
+    Store::new(move |s: &S| f(&Store { pos: s.clone(), lookup: lookup.clone() }), self.pos.clone())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::Comonad;
+  use super::Store;
+
+  #[cfg(test)]
+  mod laws {
+    use crate::Comonad;
+    use super::Store;
+
+    type Line = Store<i32, i32>;
+
+    fn same(a: &Line, b: &Line) {
+      assert_eq!(a.pos(), b.pos());
+      for s in -5..5 {
+        assert_eq!(a.peek(&s), b.peek(&s));
+      }
+    }
+
+    #[test]
+    fn comonad() {
+      let w = Store::new(|i: &i32| i * 3 - 1, 2);
+      let f = |w: &Line| w.peeks(|i| i - 1) + w.peeks(|i| i + 1);
+      let g = |w: &Line| w.extract() * w.pos();
+      same(&w, &w.extend(|w| w.extract()));
+      assert_eq!(f(&w), w.extend(f).extract());
+      same(&w.extend(g).extend(f), &w.extend(move |w| f(&w.extend(g))));
+    }
+  }
+
+  #[test]
+  fn store() {
+    let w = Store::new(|i: &i32| i * 2, 1);
+    assert_eq!((2, 6), (w.extract(), w.peek(&3)));
+    assert_eq!(10, w.seek(5).extract());
+    assert_eq!(4, w.seeks(|i| i + 1).extract());
+    assert_eq!(0, w.peeks(|i| i - 1));
+    let d = w.duplicate();
+    assert_eq!((1, 3), (*d.extract().pos(), *d.peek(&3).pos()));
+  }
+
+  #[test]
+  fn tabulate() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+    let calls = Rc::new(Cell::new(0));
+    let counter = calls.clone();
+    let w = Store::new(move |i: &i32| { counter.set(counter.get() + 1); *i }, 0).tabulate(0..4);
+    assert_eq!(4, calls.get());
+    assert_eq!(vec![0, 1, 2, 3], (0..4).map(|i| w.peek(&i)).collect::<Vec<_>>());
+    assert_eq!(4, calls.get());
+    assert_eq!(9, w.peek(&9));
+    assert_eq!(5, calls.get());
+  }
+
+  #[test]
+  fn cellular_automaton() {
+    // rule 90 on a ring of 7 cells, every cell becomes the xor of its neighbours
+    let width = 7;
+    let start = Store::new(move |i: &i32| *i == 3, 0);
+    let step = move |w: &Store<i32, bool>| w.peeks(|i| (i + width - 1) % width) ^ w.peeks(|i| (i + 1) % width);
+    let mut w = start;
+    let mut rows = vec![];
+    for _ in 0..4 {
+      rows.push((0..width).map(|i| if w.peek(&i) { '#' } else { '.' }).collect::<String>());
+      w = w.extend(step).tabulate(0..width);
+    }
+    assert_eq!(vec!["...#...", "..#.#..", ".#...#.", "#.#.#.#"], rows);
+  }
+}
@@ -0,0 +1,205 @@
+//!
+//! Traversable implementations and tests
+//!
+
+use crate::{Applicative, Functor, Kind, Traversable, TraverseStep};
+use std::boxed::Box;
+use std::rc::Rc;
+
+impl<A> Traversable for Vec<A> {
+  fn traverse<'a, B, GB, F>(&self, f: F) -> GB::Target<Vec<B>>
+    where F: Fn(&A) -> GB,
+          B: Clone + 'a,
+          GB: Functor<A = B>,
+          GB::Target<Vec<B>>: Applicative<A = Vec<B>>
+            + Kind<Target<Vec<B>> = GB::Target<Vec<B>>>
+            + Kind<Target<TraverseStep<'a, Vec<B>>> = GB::Target<TraverseStep<'a, Vec<B>>>> {
+    traverse_slice(self, &f)
+  }
+}
+
+/// Traverse the two halves of a slice and append them, so that every value is
+/// cloned once per level rather than once per element
+fn traverse_slice<'a, A, B, GB, F>(s: &[A], f: &F) -> GB::Target<Vec<B>>
+  where F: Fn(&A) -> GB,
+        B: Clone + 'a,
+        GB: Functor<A = B>,
+        GB::Target<Vec<B>>: Applicative<A = Vec<B>>
+          + Kind<Target<Vec<B>> = GB::Target<Vec<B>>>
+          + Kind<Target<TraverseStep<'a, Vec<B>>> = GB::Target<TraverseStep<'a, Vec<B>>>> {
+  match s.len() {
+    0 => GB::Target::<Vec<B>>::pure_(vec![]),
+    1 => f(&s[0]).fmap(|b| vec![b.clone()]),
+    n => {
+      let (l, r) = s.split_at(n / 2);
+      let l = traverse_slice(l, f);
+      let r = traverse_slice(r, f);
+      l.ap::<Vec<B>, TraverseStep<'a, Vec<B>>>(r.fmap(|r| {
+        let r = r.clone();
+        Box::new(move |l: &Vec<B>| l.iter().chain(r.iter()).cloned().collect()) as TraverseStep<'a, Vec<B>>
+      }))
+    }
+  }
+}
+
+impl<A> Traversable for Option<A> {
+  fn traverse<'a, B, GB, F>(&self, f: F) -> GB::Target<Option<B>>
+    where F: Fn(&A) -> GB,
+          B: Clone + 'a,
+          GB: Functor<A = B>,
+          GB::Target<Option<B>>: Applicative<A = Option<B>>
+            + Kind<Target<Option<B>> = GB::Target<Option<B>>>
+            + Kind<Target<TraverseStep<'a, Option<B>>> = GB::Target<TraverseStep<'a, Option<B>>>> {
+    match self {
+      Some(a) => f(a).fmap(|b| Some(b.clone())),
+      None => GB::Target::<Option<B>>::pure_(None),
+    }
+  }
+}
+
+impl<A> Traversable for Box<A> {
+  fn traverse<'a, B, GB, F>(&self, f: F) -> GB::Target<Box<B>>
+    where F: Fn(&A) -> GB,
+          B: Clone + 'a,
+          GB: Functor<A = B>,
+          GB::Target<Box<B>>: Applicative<A = Box<B>>
+            + Kind<Target<Box<B>> = GB::Target<Box<B>>>
+            + Kind<Target<TraverseStep<'a, Box<B>>> = GB::Target<TraverseStep<'a, Box<B>>>> {
+    f(self).fmap(|b| Box::new(b.clone()))
+  }
+}
+
+impl<A> Traversable for Rc<A> {
+  fn traverse<'a, B, GB, F>(&self, f: F) -> GB::Target<Rc<B>>
+    where F: Fn(&A) -> GB,
+          B: Clone + 'a,
+          GB: Functor<A = B>,
+          GB::Target<Rc<B>>: Applicative<A = Rc<B>>
+            + Kind<Target<Rc<B>> = GB::Target<Rc<B>>>
+            + Kind<Target<TraverseStep<'a, Rc<B>>> = GB::Target<TraverseStep<'a, Rc<B>>>> {
+    f(self).fmap(|b| Rc::new(b.clone()))
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::Traversable;
+  use std::rc::Rc;
+
+  fn positive(i: &i32) -> Option<i32> { if *i > 0 { Some(*i) } else { None } }
+
+  #[cfg(test)]
+  mod laws {
+    use crate::{Applicative, Functor, Kind, Traversable};
+    use std::rc::Rc;
+
+    /// Option composed with Result, used for the composition law
+    #[derive(Debug, Clone, PartialEq)]
+    struct OptRes<A>(Option<Result<A, String>>);
+    hkt!(OptRes);
+
+    impl<A> Functor for OptRes<A> {
+      fn fmap<B, F>(&self, f: F) -> OptRes<B> where F: Fn(&A) -> B {
+        OptRes(self.0.fmap(|r| r.fmap(&f)))
+      }
+    }
+
+    impl<A> Applicative for OptRes<A> {
+      fn pure_(a: A) -> Self {
+        OptRes(Some(Ok(a)))
+      }
+
+      fn ap<B, F>(&self, of: OptRes<F>) -> OptRes<B> where F: Fn(&A) -> B {
+        OptRes(match (&self.0, of.0) {
+          (Some(r), Some(rf)) => Some(r.ap(rf)),
+          (_, _) => None,
+        })
+      }
+    }
+
+    fn half(i: &i32) -> Option<i32> { if i % 2 == 0 { Some(i / 2) } else { None } }
+    fn small(i: &i32) -> Result<i32, String> { if *i < 10 { Ok(*i) } else { Err(format!("{} is too big", i)) } }
+
+    macro_rules! laws {
+      ($t: ident, $v: expr) => {
+        #[allow(non_snake_case)]
+        #[test]
+        fn $t() {
+          // identity
+          let identity: Box<_> = $v.traverse(|a| Box::new(*a));
+          assert_eq!(Box::new($v), identity);
+          // composition
+          let composed: OptRes<_> = $v.traverse(|a| OptRes(half(a).fmap(small)));
+          let nested: Option<Result<_, String>> = $v.traverse(half).fmap(|tb| tb.traverse(small));
+          assert_eq!(OptRes(nested), composed);
+          // naturality, using Result::ok as the applicative transformation
+          let before: Result<_, String> = $v.traverse(small);
+          let after: Option<_> = $v.traverse(|a| small(a).ok());
+          assert_eq!(before.ok(), after);
+        }
+      };
+    }
+    laws!(Vec, vec![2, 4, 6]);
+    laws!(VecBig, vec![2, 40, 6]);
+    laws!(VecOdd, vec![2, 3, 6]);
+    laws!(Option, Some(4));
+    laws!(OptionNone, None::<i32>);
+    laws!(Box, Box::new(8));
+    laws!(Rc, Rc::new(40));
+  }
+
+  #[test]
+  fn vec() {
+    let a: Option<Vec<i32>> = vec![1, 2, 3].traverse(positive);
+    let b: Option<Vec<i32>> = vec![1, -2, 3].traverse(positive);
+    let c: Option<Vec<i32>> = vec![].traverse(positive);
+    assert_eq!(Some(vec![1, 2, 3]), a);
+    assert_eq!(None, b);
+    assert_eq!(Some(vec![]), c);
+    let d: Option<Vec<i32>> = (1..=100).collect::<Vec<_>>().traverse(positive);
+    assert_eq!(Some((1..=100).collect()), d);
+  }
+
+  #[test]
+  fn vec_result() {
+    let a: Result<Vec<i32>, &str> = vec![Ok(1), Ok(2)].sequence();
+    let b: Result<Vec<i32>, &str> = vec![Ok(1), Err("first"), Err("second")].sequence();
+    assert_eq!(Ok(vec![1, 2]), a);
+    assert_eq!(Err("first"), b);
+    let c: Result<Vec<i32>, i32> = vec![Ok(1), Err(2), Ok(3), Err(4), Err(5)].sequence();
+    assert_eq!(Err(2), c);
+  }
+
+  #[test]
+  fn option() {
+    let a: Option<Option<i32>> = Some(1).traverse(positive);
+    let b: Option<Option<i32>> = Some(-1).traverse(positive);
+    let c: Option<Option<i32>> = None.traverse(positive);
+    assert_eq!(Some(Some(1)), a);
+    assert_eq!(None, b);
+    assert_eq!(Some(None), c);
+  }
+
+  #[test]
+  fn box_() {
+    let a: Option<Box<i32>> = Box::new(1).traverse(positive);
+    let b: Option<Box<i32>> = Box::new(Some(1)).sequence();
+    assert_eq!(Some(Box::new(1)), a);
+    assert_eq!(Some(Box::new(1)), b);
+  }
+
+  #[test]
+  fn rc() {
+    let a: Option<Rc<i32>> = Rc::new(1).traverse(positive);
+    let b: Option<Rc<i32>> = Rc::new(-1).traverse(positive);
+    assert_eq!(Some(Rc::new(1)), a);
+    assert_eq!(None, b);
+  }
+
+  #[test]
+  fn traverse_() {
+    assert_eq!(Some(()), vec![1, 2].traverse_(positive));
+    assert_eq!(None, vec![1, -2].traverse_(positive));
+    assert_eq!(None, vec![-1].for_each_m(positive));
+  }
+}
@@ -0,0 +1,244 @@
+//!
+//! Validation implementations and tests
+//!
+//! Validation is like Result, except that its Applicative collects every
+//! failure with `Semigroup::mappend` instead of stopping at the first one.
+//!
+//! ```
+//! use funlib::{Applicative, Functor};
+//! use funlib::validation::Validation;
+//! use funlib::validation::Validation::*;
+//!
+//! // counts the values that are not positive
+//! fn positive(i: i32) -> Validation<u32, i32> {
+//!   if i > 0 { Success(i) } else { Failure(1) }
+//! }
+//! fn add(a: i32, b: i32) -> Validation<u32, i32> {
+//!   positive(a).ap(positive(b).fmap(|&b| move |a: &i32| a + b))
+//! }
+//! assert_eq!(Success(3), add(2, 1));
+//! assert_eq!(Failure(2), add(-2, -1));
+//! ```
+
+use crate::{Applicative, Bifunctor, Functor, Kind, Kind2, Semigroup};
+use crate::Foldable::Foldable;
+use crate::nonempty::NonEmpty;
+
+/// Either a successful value or the failures collected so far
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Validation<E, A> {
+  /// The collected failures
+  Failure(E),
+  /// The successful value
+  Success(A),
+}
+
+hkt!(Validation<E, _>);
+hkt!(Validation<_, _>);
+
+use Validation::*;
+
+impl<E, A> Validation<E, A> {
+  /// Create a failed Validation holding a single error in a Vec
+  pub fn failure_vec(e: E) -> Validation<Vec<E>, A> {
+    Failure(vec![e])
+  }
+
+  /// Create a failed Validation holding a single error in a NonEmpty
+  pub fn failure_nel(e: E) -> Validation<NonEmpty<E>, A> {
+    Failure(NonEmpty::new(e))
+  }
+
+  /// Wrap the failure in a Vec so that it can be collected with others
+  pub fn into_vec(self) -> Validation<Vec<E>, A> {
+    self.map_failure(|e| vec![e])
+  }
+
+  /// Wrap the failure in a NonEmpty so that it can be collected with others
+  pub fn into_nel(self) -> Validation<NonEmpty<E>, A> {
+    self.map_failure(NonEmpty::new)
+  }
+
+  /// Transform the failure value
+  pub fn map_failure<E2, F>(self, f: F) -> Validation<E2, A> where F: FnOnce(E) -> E2 {
+    match self {
+      Failure(e) => Failure(f(e)),
+      Success(a) => Success(a),
+    }
+  }
+
+  /// Check if the Validation succeeded
+  pub fn is_success(&self) -> bool {
+    matches!(self, Success(_))
+  }
+
+  /// Check if the Validation failed
+  pub fn is_failure(&self) -> bool {
+    !self.is_success()
+  }
+
+  /// Convert into a Result, failures become `Err`
+  pub fn into_result(self) -> Result<A, E> {
+    self.into()
+  }
+
+}
+
+impl<E, A> From<Result<A, E>> for Validation<E, A> {
+  fn from(r: Result<A, E>) -> Self {
+    match r {
+      Ok(a) => Success(a),
+      Err(e) => Failure(e),
+    }
+  }
+}
+
+impl<E, A> From<Validation<E, A>> for Result<A, E> {
+  fn from(v: Validation<E, A>) -> Self {
+    match v {
+      Success(a) => Ok(a),
+      Failure(e) => Err(e),
+    }
+  }
+}
+
+impl<E: Clone, A> Functor for Validation<E, A> {
+  fn fmap<B, F>(&self, f: F) -> Validation<E, B> where F: Fn(&A) -> B {
+    match self {
+      Success(a) => Success(f(a)),
+      Failure(e) => Failure(e.clone()),
+    }
+  }
+}
+
+/// `first` maps the failures and `second` the successful value
+impl<E, A> Bifunctor for Validation<E, A> {
+  fn bimap<C, D, F, G>(&self, f: F, g: G) -> Validation<C, D> where F: Fn(&E) -> C, G: Fn(&A) -> D {
+    match self {
+      Failure(e) => Failure(f(e)),
+      Success(a) => Success(g(a)),
+    }
+  }
+}
+
+impl<E: Semigroup, A> Applicative for Validation<E, A> {
+  fn pure_(a: A) -> Self {
+    Success(a)
+  }
+
+  fn ap<B, F>(&self, of: Validation<E, F>) -> Validation<E, B> where F: Fn(&A) -> B {
+    match (self, of) {
+      (Success(a), Success(f)) => Success(f(a)),
+      (Failure(e1), Failure(e2)) => Failure(e1.mappend(&e2)),
+      (Failure(e), _) => Failure(e.clone()),
+      (_, Failure(e)) => Failure(e),
+    }
+  }
+}
+
+impl<E, A> Foldable for Validation<E, A> {
+  type Iter<'a> = std::option::IntoIter<&'a A> where E: 'a, A: 'a;
+  fn iter(&self) -> Self::Iter<'_> {
+    match self {
+      Success(a) => Some(a),
+      Failure(_) => None,
+    }.into_iter()
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::{Applicative, Functor};
+  use crate::Foldable::*;
+  use crate::nonempty::NonEmpty;
+  use super::Validation;
+  use super::Validation::*;
+
+  /// Counts the odd values
+  fn even(i: i32) -> Validation<u32, i32> {
+    if i % 2 == 0 { Success(i) } else { Failure(1) }
+  }
+
+  fn small(i: i32) -> Validation<NonEmpty<i32>, i32> {
+    if i < 10 { Success(i) } else { Validation::failure_nel(i) }
+  }
+
+  #[cfg(test)]
+  mod laws {
+    use crate::{Applicative, Functor};
+    use super::Validation;
+    use super::Validation::*;
+
+    fn id<A>(v: A) -> A { v }
+    macro_rules! laws {
+      ($t: ident, $v: expr, $f: expr, $f2: expr) => {
+        #[allow(non_snake_case)]
+        #[test]
+        fn $t() {
+          assert_eq!($v, $v.fmap(|x| *id(x)));
+          assert_eq!($v.fmap(|x| compose!($f, $f2)(x)), $v.fmap($f).fmap($f2))
+        }
+      };
+    }
+    laws!(success, Validation::<u8, i32>::Success(5), |x| x + 2, |x| x * 5);
+    laws!(failure, Validation::<u8, i32>::Failure(5), |x| x + 2, |x| x * 5);
+    #[test]
+    fn applicative_identity() {
+      let v: Validation<u8, i32> = Success(4);
+      let e: Validation<u8, i32> = Failure(1);
+      assert_eq!(v, v.ap(Validation::<u8, fn(&i32) -> i32>::Success(|x| *x)));
+      assert_eq!(e, e.ap(Validation::<u8, fn(&i32) -> i32>::Success(|x| *x)));
+    }
+  }
+
+  #[test]
+  fn accumulates_failures() {
+    let r = even(1).ap(even(3).fmap(|&b| move |a: &i32| a + b));
+    assert_eq!(Failure(2), r);
+    let r = even(1).ap(even(4).fmap(|&b| move |a: &i32| a + b));
+    assert_eq!(Failure(1), r);
+  }
+
+  #[test]
+  fn accumulates_nel() {
+    let r = small(11).ap(small(12).fmap(|&b| move |a: &i32| a + b));
+    assert_eq!(Failure(NonEmpty { head: 11, tail: vec![12] }), r);
+    let r = small(1).ap(small(12).fmap(|&b| move |a: &i32| a + b));
+    assert_eq!(Failure(NonEmpty::new(12)), r);
+  }
+
+  #[test]
+  fn success() {
+    assert_eq!(Success(6), even(2).ap(even(4).fmap(|&b| move |a: &i32| a + b)));
+    assert_eq!(Validation::<u32, i32>::pure_(1), Success(1));
+  }
+
+  #[test]
+  fn result_conversion() {
+    assert_eq!(Success::<&str, i32>(1), Validation::from(Ok(1)));
+    assert_eq!(Failure::<&str, i32>("bad"), Validation::from(Err("bad")));
+    assert_eq!(Ok(1), Success::<&str, i32>(1).into_result());
+    assert_eq!(Err("bad"), Failure::<&str, i32>("bad").into_result());
+  }
+
+  #[test]
+  fn into_collections() {
+    assert_eq!(Failure::<Vec<&str>, i32>(vec!["bad"]), Failure("bad").into_vec());
+    assert_eq!(Failure::<Vec<&str>, i32>(vec!["bad"]), Validation::failure_vec("bad"));
+    assert_eq!(Failure::<NonEmpty<&str>, i32>(NonEmpty::new("bad")), Failure("bad").into_nel());
+  }
+
+  #[test]
+  fn bifunctor() {
+    use crate::Bifunctor;
+    assert_eq!(Failure::<String, i32>("1 odd".to_string()), even(1).bimap(|e| format!("{} odd", e), |a| a * 2));
+    assert_eq!(Success::<String, i32>(4), even(2).bimap(|e| e.to_string(), |a| a * 2));
+    assert_eq!(Failure::<Vec<usize>, i32>(vec![12]), small(12).first(|n| n.fmap(|e| *e as usize).into_vec()));
+  }
+
+  #[test]
+  fn fold() {
+    assert_eq!(5, even(2).fold_left(3, |b, &a| a + b));
+    assert_eq!(3, even(1).fold_right(3, |&a, b| a + b));
+  }
+}
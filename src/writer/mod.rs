@@ -0,0 +1,222 @@
+//!
+//! Writer implementations and tests
+//!
+
+use crate::{Applicative, Functor, Kind, Monad, Monoid};
+
+hkt!(Writer<W, _>);
+
+/// A value together with the output written while computing it
+///
+/// The output is combined with `Semigroup::mappend`, earlier output comes first.
+/// Use a `Vec<String>` for a log or a number for a counter.
+///
+/// # Examples
+///
+/// ```
+/// use funlib::{Functor, Monad};
+/// use funlib::writer::Writer;
+/// fn half(i: &u32) -> Writer<Vec<String>, u32> {
+///   Writer::tell(vec![format!("halved {}", i)]).fmap(|_| i / 2)
+/// }
+/// let (value, log) = Writer::new(20, vec![]).bind(half).bind(half).run();
+/// assert_eq!(5, value);
+/// assert_eq!(vec!["halved 20", "halved 10"], log);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Writer<W, A> {
+  /// The computed value
+  pub value: A,
+  /// The written output
+  pub log: W,
+}
+
+impl<W, A> Writer<W, A> {
+  /// Create a Writer from a value and its output
+  pub fn new(value: A, log: W) -> Self {
+    Writer { value, log }
+  }
+
+  /// Split into the value and the output
+  pub fn run(self) -> (A, W) {
+    (self.value, self.log)
+  }
+
+  /// Change the output written so far
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use funlib::writer::Writer;
+  /// let w = Writer::new(1, vec!["secret".to_string()]);
+  /// assert_eq!(vec!["***"], w.censor(|log| log.iter().map(|_| "***".to_string()).collect()).log);
+  /// ```
+  pub fn censor<F>(self, f: F) -> Self where F: FnOnce(W) -> W {
+    Writer { value: self.value, log: f(self.log) }
+  }
+
+  /// Pair the value with the output written so far
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use funlib::writer::Writer;
+  /// assert_eq!(Writer::new((1, 3u32), 3u32), Writer::new(1, 3u32).listen());
+  /// ```
+  pub fn listen(&self) -> Writer<W, (A, W)> where A: Clone, W: Clone {
+    Writer { value: (self.value.clone(), self.log.clone()), log: self.log.clone() }
+  }
+}
+
+impl<W> Writer<W, ()> {
+  /// Write output without a value
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use funlib::Monad;
+  /// use funlib::writer::Writer;
+  /// let counted = Writer::tell(1u32).then(Writer::tell(2u32)).then(Writer::new("done", 0u32));
+  /// assert_eq!(("done", 3), counted.run());
+  /// ```
+  pub fn tell(log: W) -> Self {
+    Writer { value: (), log }
+  }
+}
+
+impl<W, A, F> Writer<W, (A, F)> where F: FnOnce(W) -> W {
+  /// Change the output with the function paired with the value
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use funlib::writer::Writer;
+  /// let w = Writer::new((1, |log: Vec<i32>| log.into_iter().rev().collect()), vec![1, 2]);
+  /// assert_eq!(Writer::new(1, vec![2, 1]), w.pass());
+  /// ```
+  pub fn pass(self) -> Writer<W, A> {
+    let (value, f) = self.value;
+    Writer { value, log: f(self.log) }
+  }
+}
+
+impl<W: Clone, A> Functor for Writer<W, A> {
+  fn fmap<B, F>(&self, f: F) -> Writer<W, B> where F: Fn(&A) -> B {
+    Writer { value: f(&self.value), log: self.log.clone() }
+  }
+}
+
+impl<W: Monoid, A> Applicative for Writer<W, A> {
+  fn pure_(a: A) -> Self {
+    Writer { value: a, log: W::mempty() }
+  }
+
+  fn ap<B, F>(&self, of: Writer<W, F>) -> Writer<W, B> where F: Fn(&A) -> B {
+    Writer { value: (of.value)(&self.value), log: self.log.mappend(&of.log) }
+  }
+}
+
+impl<W: Monoid, A> Monad for Writer<W, A> {
+  fn bind<B, F>(&self, mut f: F) -> Writer<W, B> where F: FnMut(&A) -> Writer<W, B> {
+    let r = f(&self.value);
+    Writer { value: r.value, log: self.log.mappend(&r.log) }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::{Applicative, Functor, Monad};
+  use crate::semigroup::Max;
+  use super::Writer;
+
+  #[cfg(test)]
+  mod laws {
+    use crate::{Applicative, Functor, Monad};
+    use super::Writer;
+
+    type Logged = Writer<Vec<String>, i32>;
+
+    fn f(a: &i32) -> Logged { Writer::new(a * 10, vec![format!("f {}", a)]) }
+    fn g(a: &i32) -> Logged { Writer::new(a + 1, vec![format!("g {}", a)]) }
+
+    #[test]
+    fn functor() {
+      let w = f(&2);
+      assert_eq!(w, w.fmap(|x| *x));
+      assert_eq!(w.fmap(|x| compose!(|x| x + 2, |x| x * 5)(x)), w.fmap(|x| x + 2).fmap(|x| x * 5));
+    }
+
+    #[test]
+    fn applicative() {
+      let w = f(&2);
+      assert_eq!(w, w.ap(Writer::<Vec<String>, fn(&i32) -> i32>::pure_(|x| *x)));
+      assert_eq!(Logged::pure_(3), Logged::pure_(2).ap(Writer::<Vec<String>, fn(&i32) -> i32>::pure_(|x| x + 1)));
+      let fs: Writer<Vec<String>, fn(&i32) -> i32> = Writer::new(|x| x - 1, vec!["fs".to_string()]);
+      assert_eq!(w.ap(fs.clone()), w.bind(|a| fs.fmap(|f| f(a))));
+    }
+
+    #[test]
+    fn monad() {
+      let w = f(&2);
+      assert_eq!(f(&4), Logged::pure_(4).bind(f));
+      assert_eq!(w, w.bind(|a| Logged::pure_(*a)));
+      assert_eq!(w.bind(f).bind(g), w.bind(|a| f(a).bind(g)));
+    }
+
+    #[test]
+    fn counter() {
+      let w: Writer<u32, i32> = Writer::new(2, 3);
+      let h = |a: &i32| Writer::new(a * 2, 1u32);
+      let k = |a: &i32| Writer::new(a + 1, 5u32);
+      assert_eq!(w, w.bind(|a| Writer::pure_(*a)));
+      assert_eq!(w.bind(h).bind(k), w.bind(|a| h(a).bind(k)));
+    }
+  }
+
+  fn withdraw(balance: &u32, amount: u32) -> Writer<Vec<String>, u32> {
+    if amount > *balance {
+      Writer::new(*balance, vec![format!("refused {} of {}", amount, balance)])
+    } else {
+      Writer::new(balance - amount, vec![format!("withdrew {}", amount)])
+    }
+  }
+
+  #[test]
+  fn audit_trail() {
+    let (balance, log) = Writer::new(100, vec!["opened with 100".to_string()])
+      .bind(|b| withdraw(b, 30))
+      .bind(|b| withdraw(b, 90))
+      .bind(|b| withdraw(b, 70))
+      .run();
+    assert_eq!(0, balance);
+    assert_eq!(vec!["opened with 100", "withdrew 30", "refused 90 of 70", "withdrew 70"], log);
+  }
+
+  #[test]
+  fn counters() {
+    let step = |a: &i32| Writer::tell(1u32).fmap(move |_| a * 2);
+    assert_eq!((16, 3), Writer::pure_(2).bind(step).bind(step).bind(step).run());
+    let peak = |a: &i32| Writer::new(*a, Max(*a));
+    assert_eq!((5, Max(7)), Writer::new(3, Max(3)).bind(|_| peak(&7)).bind(|_| peak(&5)).listen().value);
+  }
+
+  #[test]
+  fn listen_pass_censor() {
+    let w: Writer<Vec<String>, i32> = Writer::new(1, vec!["one".to_string()]);
+    assert_eq!(Writer::new((1, vec!["one".to_string()]), vec!["one".to_string()]), w.listen());
+    let upper = w.clone().fmap(|a| (*a, |log: Vec<String>| log.iter().map(|l| l.to_uppercase()).collect())).pass();
+    assert_eq!(Writer::new(1, vec!["ONE".to_string()]), upper);
+    assert_eq!(Writer::new(1, vec![]), w.censor(|_| vec![]));
+  }
+
+  #[test]
+  fn mdo() {
+    let w: Writer<Vec<&str>, i32> = mdo! {
+      a <- Writer::new(1, vec!["a"]);
+      _ <- Writer::tell(vec!["between"]);
+      b <- Writer::new(2, vec!["b"]);
+      pure a + b
+    };
+    assert_eq!((3, vec!["a", "between", "b"]), w.run());
+  }
+}
@@ -0,0 +1,133 @@
+//!
+//! ZipList implementations and tests
+//!
+
+use crate::{Applicative, Functor, Kind};
+
+hkt!(ZipList);
+
+/// A list whose Applicative zips values with functions by position instead of
+/// applying every function to every value like `Vec`
+///
+/// `pure_` creates a value repeated forever, so it lines up with a list of any length.
+///
+/// # Examples
+///
+/// ```
+/// use funlib::Applicative;
+/// use funlib::ziplist::ZipList;
+/// let fs: ZipList<fn(&i32) -> i32> = ZipList::new(vec![|x| x + 1, |x| x * 10]);
+/// assert_eq!(ZipList::from(vec![2, 20]), ZipList::from(vec![1, 2, 3]).ap(fs));
+/// let sums = ZipList::from(vec![1, 2]).map2(&ZipList::from(vec![10, 20, 30]), |a, b| a + b);
+/// assert_eq!(vec![11, 22], sums.into_vec());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ZipList<A> {
+  /// A single value repeated forever
+  Repeat(A),
+  /// A finite list of values
+  List(Vec<A>),
+}
+
+use ZipList::*;
+
+impl<A> ZipList<A> {
+  /// Create a ZipList from the values of a Vec
+  pub fn new(values: Vec<A>) -> Self {
+    List(values)
+  }
+
+  /// Convert the ZipList into a Vec, a repeated value gives a single element
+  pub fn into_vec(self) -> Vec<A> {
+    match self {
+      Repeat(a) => vec![a],
+      List(v) => v,
+    }
+  }
+}
+
+impl<A> From<Vec<A>> for ZipList<A> {
+  fn from(values: Vec<A>) -> Self {
+    List(values)
+  }
+}
+
+impl<A> Functor for ZipList<A> {
+  fn fmap<B, F>(&self, f: F) -> ZipList<B> where F: Fn(&A) -> B {
+    match self {
+      Repeat(a) => Repeat(f(a)),
+      List(v) => List(v.iter().map(f).collect()),
+    }
+  }
+}
+
+/// Applies the functions to the values at the same position, the result is as long as the shorter list
+impl<A> Applicative for ZipList<A> {
+  fn pure_(a: A) -> Self {
+    Repeat(a)
+  }
+
+  fn ap<B, F>(&self, of: ZipList<F>) -> ZipList<B> where F: Fn(&A) -> B {
+    match (self, of) {
+      (Repeat(a), Repeat(f)) => Repeat(f(a)),
+      (Repeat(a), List(fs)) => List(fs.iter().map(|f| f(a)).collect()),
+      (List(v), Repeat(f)) => List(v.iter().map(f).collect()),
+      (List(v), List(fs)) => List(v.iter().zip(fs.iter()).map(|(a, f)| f(a)).collect()),
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::{Applicative, Functor};
+  use super::ZipList::{self, *};
+
+  #[cfg(test)]
+  mod laws {
+    use crate::{Applicative, Functor};
+    use super::ZipList::{self, *};
+
+    macro_rules! laws {
+      ($n: ident, $v: expr) => {
+        #[test]
+        fn $n() {
+          let v: ZipList<i32> = $v;
+          let fs: ZipList<fn(&i32) -> i32> = List(vec![|x| x + 1, |x| x * 10, |x| x - 3]);
+          let gs: ZipList<fn(&i32) -> i32> = List(vec![|x| x * 2, |x| x + 7]);
+          assert_eq!(v, v.fmap(|x| *x));
+          assert_eq!(v, v.ap(ZipList::<fn(&i32) -> i32>::pure_(|x| *x)));
+          assert_eq!(ZipList::pure_(3), ZipList::pure_(2).ap(ZipList::<fn(&i32) -> i32>::pure_(|x| x + 1)));
+          // interchange
+          assert_eq!(ZipList::pure_(4).ap(fs.clone()), fs.ap(ZipList::pure_(|f: &fn(&i32) -> i32| f(&4))));
+          // composition, applying fs and then gs equals applying their pointwise composition
+          let composed = fs.map2(&gs, |f, g| { let (f, g) = (*f, *g); move |x: &i32| g(&f(x)) });
+          assert_eq!(v.ap(fs).ap(gs), v.ap(composed));
+        }
+      };
+    }
+    laws!(list, List(vec![1, 2, 3, 4]));
+    laws!(short, List(vec![5]));
+    laws!(empty, List(vec![]));
+    laws!(repeat, Repeat(2));
+  }
+
+  #[test]
+  fn zips() {
+    let f1: &dyn Fn(&i32) -> i32 = &|x| x + 6;
+    let f2: &dyn Fn(&i32) -> i32 = &|x| x * x;
+    let f3: &dyn Fn(&i32) -> i32 = &|x| x * x * x;
+    assert_eq!(List(vec![7, 4, 27]), List(vec![1, 2, 3]).ap(List(vec![f1, f2, f3])));
+    assert_eq!(List(vec![7]), List(vec![1]).ap(List(vec![f1, f2, f3])));
+    assert_eq!(List(vec![7, 8]), List(vec![1, 2]).ap(ZipList::pure_(f1)));
+  }
+
+  #[test]
+  fn map_n() {
+    let names = ZipList::from(vec!["a", "b", "c"]);
+    let ages = ZipList::from(vec![1, 2]);
+    assert_eq!(List(vec![("a", 1), ("b", 2)]), names.zip(&ages));
+    assert_eq!(List(vec!["a1".to_string(), "b1".to_string(), "c1".to_string()]), names.map2(&ZipList::pure_(1), |n, a| format!("{}{}", n, a)));
+    assert_eq!(vec![2, 4], ages.fmap(|a| a * 2).into_vec());
+    assert_eq!(vec![1], ZipList::pure_(1).into_vec());
+  }
+}